@@ -0,0 +1,259 @@
+use crate::{BowlingGame, Error, Rules};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+/// Several bowlers sharing a lane, each bowling a full frame before the next one's turn.
+#[derive(Debug, Clone)]
+pub struct Match {
+    bowlers: Vec<(String, BowlingGame)>,
+    turn: usize,
+}
+
+impl Match {
+    pub fn new(bowlers: &[&str]) -> Result<Self, Error> {
+        Self::with_rules(bowlers, Rules::default())
+    }
+
+    pub fn with_rules(bowlers: &[&str], rules: Rules) -> Result<Self, Error> {
+        check_unique(bowlers)?;
+        Ok(Self {
+            bowlers: bowlers
+                .iter()
+                .map(|name| (name.to_string(), BowlingGame::with_rules(rules)))
+                .collect(),
+            turn: 0,
+        })
+    }
+
+    pub fn current_bowler(&self) -> Option<&str> {
+        if self.is_complete() {
+            return None;
+        }
+        self.bowlers.get(self.turn).map(|(name, _)| name.as_str())
+    }
+
+    pub fn roll(&mut self, bowler: &str, pins: u16) -> Result<(), Error> {
+        let index = self
+            .bowlers
+            .iter()
+            .position(|(name, _)| name == bowler)
+            .ok_or(Error::UnknownBowler)?;
+        if self.is_complete() {
+            return Err(Error::GameComplete);
+        }
+        if index != self.turn {
+            return Err(Error::NotYourTurn);
+        }
+
+        let game = &mut self.bowlers[index].1;
+        let frames = game.frames.len();
+        game.roll(pins)?;
+
+        if game.frames.len() > frames {
+            self.turn = (self.turn + 1) % self.bowlers.len();
+        }
+        Ok(())
+    }
+
+    pub fn game(&self, bowler: &str) -> Option<&BowlingGame> {
+        self.bowlers
+            .iter()
+            .find(|(name, _)| name == bowler)
+            .map(|(_, game)| game)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.bowlers.iter().all(|(_, game)| game.is_complete())
+    }
+
+    /// Bowlers and their running scores, best first.
+    pub fn standings(&self) -> Vec<(&str, u16)> {
+        let mut standings: Vec<_> = self
+            .bowlers
+            .iter()
+            .map(|(name, game)| (name.as_str(), game.running_score()))
+            .collect();
+        standings.sort_by_key(|&(_, score)| Reverse(score));
+        standings
+    }
+}
+
+/// Turns are tracked by name, so every bowler needs a distinct one.
+fn check_unique(bowlers: &[&str]) -> Result<(), Error> {
+    let mut seen = HashSet::new();
+    if bowlers.iter().all(|name| seen.insert(name)) {
+        Ok(())
+    } else {
+        Err(Error::DuplicateBowler)
+    }
+}
+
+/// Handicap awarded per game as a percentage of the gap between a base and the bowler's average.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Handicap {
+    base: u16,
+    percentage: u16,
+}
+
+impl Handicap {
+    pub fn new(base: u16, percentage: u16) -> Self {
+        Self { base, percentage }
+    }
+
+    pub fn for_average(&self, average: u16) -> u16 {
+        let gap = u32::from(self.base.saturating_sub(average));
+        (gap * u32::from(self.percentage) / 100) as u16
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub bowler: String,
+    pub scratch: u32,
+    pub handicap: u32,
+    pub total: u32,
+}
+
+/// A league night: the same bowlers playing a fixed number of matches in a row.
+#[derive(Debug, Clone)]
+pub struct Series {
+    bowlers: Vec<String>,
+    rules: Rules,
+    games: usize,
+    matches: Vec<Match>,
+    handicap: Option<Handicap>,
+    averages: HashMap<String, u16>,
+}
+
+impl Series {
+    /// A standard three-game series.
+    pub fn new(bowlers: &[&str]) -> Result<Self, Error> {
+        check_unique(bowlers)?;
+        Ok(Self {
+            bowlers: bowlers.iter().map(|name| name.to_string()).collect(),
+            rules: Rules::default(),
+            games: 3,
+            matches: vec![],
+            handicap: None,
+            averages: HashMap::new(),
+        })
+    }
+
+    pub fn with_games(&self, games: usize) -> Self {
+        let mut series = self.clone();
+        series.games = games;
+        series
+    }
+
+    pub fn with_rules(&self, rules: Rules) -> Self {
+        let mut series = self.clone();
+        series.rules = rules;
+        series
+    }
+
+    pub fn with_handicap(&self, handicap: Handicap) -> Self {
+        let mut series = self.clone();
+        series.handicap = Some(handicap);
+        series
+    }
+
+    /// Sets the established average a bowler's handicap is worked out from.
+    pub fn with_average(&self, bowler: &str, average: u16) -> Self {
+        let mut series = self.clone();
+        series.averages.insert(bowler.to_string(), average);
+        series
+    }
+
+    pub fn matches(&self) -> &[Match] {
+        &self.matches
+    }
+
+    pub fn current_bowler(&self) -> Option<&str> {
+        match self.matches.last() {
+            Some(current) if !current.is_complete() => current.current_bowler(),
+            _ if self.is_complete() => None,
+            _ => self.bowlers.first().map(|name| name.as_str()),
+        }
+    }
+
+    /// Rolls in the current match, starting the next one once everybody has finished.
+    pub fn roll(&mut self, bowler: &str, pins: u16) -> Result<(), Error> {
+        if !self.bowlers.iter().any(|name| name == bowler) {
+            return Err(Error::UnknownBowler);
+        }
+        if self.is_complete() {
+            return Err(Error::GameComplete);
+        }
+        if self.current_bowler() != Some(bowler) {
+            return Err(Error::NotYourTurn);
+        }
+
+        if self.matches.last().is_none_or(Match::is_complete) {
+            let bowlers: Vec<&str> = self.bowlers.iter().map(|name| name.as_str()).collect();
+            let mut next = Match::with_rules(&bowlers, self.rules)?;
+            next.roll(bowler, pins)?;
+            self.matches.push(next);
+            return Ok(());
+        }
+
+        self.matches.last_mut().unwrap().roll(bowler, pins)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.matches.len() == self.games && self.matches.iter().all(Match::is_complete)
+    }
+
+    /// Pins knocked down over the whole series, without handicap. A `u32`, since a series
+    /// can have any number of games.
+    pub fn series_total(&self, bowler: &str) -> Option<u32> {
+        self.matches
+            .iter()
+            .map(|game| {
+                game.game(bowler)
+                    .map(|game| u32::from(game.running_score()))
+            })
+            .sum::<Option<u32>>()
+            .filter(|_| self.bowlers.iter().any(|name| name == bowler))
+    }
+
+    /// Handicap per game for a bowler, zero without a handicap or a known average.
+    pub fn handicap(&self, bowler: &str) -> u16 {
+        match (self.handicap, self.averages.get(bowler)) {
+            (Some(handicap), Some(&average)) => handicap.for_average(average),
+            _ => 0,
+        }
+    }
+
+    /// Bowlers ranked by series total including handicap for every game started.
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut standings: Vec<_> = self
+            .bowlers
+            .iter()
+            .map(|bowler| {
+                let scratch = self.series_total(bowler).unwrap_or(0);
+                let games = u32::try_from(self.matches.len()).unwrap_or(u32::MAX);
+                let handicap = u32::from(self.handicap(bowler)).saturating_mul(games);
+                Standing {
+                    bowler: bowler.clone(),
+                    scratch,
+                    handicap,
+                    total: scratch.saturating_add(handicap),
+                }
+            })
+            .collect();
+        standings.sort_by_key(|standing| Reverse(standing.total));
+        standings
+    }
+
+    /// The best finished game in the series and who bowled it.
+    pub fn high_game(&self) -> Option<(&str, u16)> {
+        self.matches
+            .iter()
+            .flat_map(|game| &game.bowlers)
+            .filter_map(|(name, game)| game.score().map(|score| (name.as_str(), score)))
+            .fold(None, |best, (name, score)| match best {
+                Some((_, high)) if high >= score => best,
+                _ => Some((name, score)),
+            })
+    }
+}
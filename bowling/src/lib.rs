@@ -1,150 +1,338 @@
+mod league;
+mod notation;
+#[cfg(feature = "serde")]
+mod persistence;
+mod pins;
+mod projection;
+mod rules;
+mod scorecard;
+mod stats;
+
+pub use league::{Handicap, Match, Series, Standing};
+pub use pins::PinSet;
+pub use rules::Rules;
+pub use stats::{stats_by_bowler, BowlerStats};
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum Error {
     NotEnoughPinsLeft,
     GameComplete,
+    /// Scorecard notation couldn't be read; `position` is the offending character's index.
+    InvalidNotation {
+        position: usize,
+        reason: NotationError,
+    },
+    UnknownBowler,
+    NotYourTurn,
+    /// The previous ball in this rack was rolled by count, so which pins stand is unknown.
+    StandingPinsUnknown,
+    /// Pin-by-pin tracking needs a full rack of ten pins.
+    PinTrackingUnsupported,
+    PinsNotStanding,
+    /// The same name was given for two bowlers in a match or series.
+    DuplicateBowler,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NotationError {
+    UnexpectedSymbol(char),
+    SpareOnFirstBall,
+    StrikeAfterFirstBall,
+    NotEnoughPinsLeft,
+    GameComplete,
+    /// A new frame was started before the previous one was finished.
+    FrameNotFinished,
+    /// A frame ran on into the next one without whitespace in between.
+    MissingSeparator,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Frame {
-    Last(u16, u16, Option<u16>),
-    Open(u16, u16),
+    Last(Vec<u16>),
+    Open(Vec<u16>),
     Spare(u16, u16),
     Strike,
+    Cleared(Vec<u16>),
+}
+
+impl Frame {
+    fn rolls(&self, pins: u16) -> Vec<u16> {
+        match self {
+            Frame::Last(rolls) | Frame::Open(rolls) | Frame::Cleared(rolls) => rolls.clone(),
+            Frame::Spare(a, b) => vec![*a, *b],
+            Frame::Strike => vec![pins],
+        }
+    }
+
+    fn state(&self, pins: u16) -> FrameState {
+        match self {
+            Frame::Last(rolls) => match cleared_by(rolls, pins) {
+                Some(1) => FrameState::Strike,
+                Some(2) => FrameState::Spare,
+                Some(_) => FrameState::Cleared,
+                None => FrameState::Open,
+            },
+            Frame::Open(_) => FrameState::Open,
+            Frame::Spare(_, _) => FrameState::Spare,
+            Frame::Strike => FrameState::Strike,
+            Frame::Cleared(_) => FrameState::Cleared,
+        }
+    }
+
+    /// How many of the following rolls count towards this frame's score.
+    fn bonus_rolls(&self) -> usize {
+        match self {
+            Frame::Spare(_, _) => bonus_rolls(2),
+            Frame::Strike => bonus_rolls(1),
+            _ => 0,
+        }
+    }
 }
 
+/// How many balls it took to knock down the first rack of a frame, if it went down at all.
+fn cleared_by(rolls: &[u16], pins: u16) -> Option<usize> {
+    rolls
+        .iter()
+        .scan(0, |down, roll| {
+            *down += roll;
+            Some(*down)
+        })
+        .position(|down| down == pins)
+        .map(|balls| balls + 1)
+}
+
+/// Bonus rolls earned by clearing the rack with the given ball of a frame.
+fn bonus_rolls(ball: usize) -> usize {
+    match ball {
+        1 => 2,
+        2 => 1,
+        _ => 0,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameState {
+    /// The bowler is still rolling in this frame.
+    InProgress,
+    Open,
+    Spare,
+    Strike,
+    /// Every pin went down, but only after the second ball, e.g. candlepin's ten-box.
+    Cleared,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameScore {
+    /// Pins set up in each rack.
+    pub pins: u16,
+    pub rolls: Vec<u16>,
+    pub state: FrameState,
+    /// Running total up to and including this frame, `None` while it still waits for rolls.
+    pub cumulative: Option<u16>,
+}
+
+impl FrameScore {
+    /// A strike or spare whose bonus rolls haven't been thrown yet.
+    pub fn is_pending_bonus(&self) -> bool {
+        matches!(self.state, FrameState::Spare | FrameState::Strike) && self.cumulative.is_none()
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct BowlingGame {
+    rules: Rules,
     incomplete_frame: Option<Vec<u16>>,
     frames: Vec<Frame>,
+    rolls: Vec<u16>,
+    /// Pins left standing after each roll, when rolled with `roll_standing`.
+    leaves: Vec<Option<PinSet>>,
 }
 
 impl BowlingGame {
     pub fn new() -> Self {
+        Self::with_rules(Rules::default())
+    }
+
+    pub fn with_rules(rules: Rules) -> Self {
         Self {
+            rules,
             frames: vec![],
             incomplete_frame: None,
+            rolls: vec![],
+            leaves: vec![],
         }
     }
 
+    pub fn rules(&self) -> Rules {
+        self.rules
+    }
+
+    /// Replays a roll log, failing at the first roll `roll` would reject.
+    pub fn from_rolls(rolls: &[u16]) -> Result<Self, Error> {
+        Self::from_rolls_with_rules(Rules::default(), rolls)
+    }
+
+    pub fn from_rolls_with_rules(rules: Rules, rolls: &[u16]) -> Result<Self, Error> {
+        Self::replay(rules, rolls.iter().map(|&pins| (pins, None)))
+    }
+
+    /// Rolls by pin set where the standing pins are known, by count otherwise.
+    fn replay(
+        rules: Rules,
+        rolls: impl IntoIterator<Item = (u16, Option<PinSet>)>,
+    ) -> Result<Self, Error> {
+        let mut game = Self::with_rules(rules);
+        for (pins, leave) in rolls {
+            match leave {
+                Some(standing) => game.roll_standing(standing),
+                None => game.roll(pins),
+            }?;
+        }
+        Ok(game)
+    }
+
+    /// Every roll accepted so far, in order.
+    pub fn rolls(&self) -> &[u16] {
+        &self.rolls
+    }
+
+    /// Takes back the last roll and returns the pins it knocked down.
+    pub fn undo(&mut self) -> Option<u16> {
+        let pins = self.rolls.pop()?;
+        self.leaves.pop();
+
+        let rolls = self.rolls.iter().copied().zip(self.leaves.iter().copied());
+        *self = Self::replay(self.rules, rolls).expect("every prefix of a valid game is valid");
+
+        Some(pins)
+    }
+
     pub fn roll(&mut self, pins: u16) -> Result<(), Error> {
-        if pins > 10 {
+        if pins > self.rules.pins() {
             return Err(Error::NotEnoughPinsLeft);
         }
-        if self.frames.len() == 10 {
+        if self.is_complete() {
             return Err(Error::GameComplete);
         }
 
-        if let Some(mut prev_rolls) = self.incomplete_frame.take() {
-            // the last frame is special
-            if self.frames.len() == 9 {
-                match prev_rolls.len() {
-                    1 => {
-                        let prev_roll_strike = prev_rolls[0] == 10;
-                        if !prev_roll_strike && (prev_rolls[0] + pins) > 10 {
-                            return Err(Error::NotEnoughPinsLeft);
-                        }
-
-                        prev_rolls.push(pins);
-
-                        if prev_rolls[0] + prev_rolls[1] >= 10 {
-                            // If someone throws a strike or a spare then they get a fill ball.
-                            self.incomplete_frame = Some(prev_rolls);
-                        } else {
-                            self.frames
-                                .push(Frame::Last(prev_rolls[0], prev_rolls[1], None));
-                        }
-                    }
-                    2 => {
-                        // the_two_balls_after_a_final_strike_cannot_score_an_invalid_number_of_pins
-                        let prev_rolls_open = prev_rolls[0] + prev_rolls[1] == 10;
-                        let prev_roll_strike = prev_rolls[1] == 10;
-                        if !prev_rolls_open && !prev_roll_strike && (prev_rolls[1] + pins) > 10 {
-                            return Err(Error::NotEnoughPinsLeft);
-                        }
-
-                        self.frames
-                            .push(Frame::Last(prev_rolls[0], prev_rolls[1], Some(pins)));
-                    }
-                    _ => return Err(Error::GameComplete),
-                }
-            } else if prev_rolls.len() == 1 {
-                let total_pins = prev_rolls[0] + pins;
-                if total_pins > 10 {
-                    return Err(Error::NotEnoughPinsLeft);
-                }
-                if total_pins == 10 {
-                    self.frames.push(Frame::Spare(prev_rolls[0], pins));
-                } else {
-                    self.frames.push(Frame::Open(prev_rolls[0], pins));
-                }
-            }
-        } else if pins == 10 {
-            if self.frames.len() == 9 {
-                self.incomplete_frame = Some(vec![pins]);
-            } else {
-                self.frames.push(Frame::Strike);
-            }
-        } else {
-            self.incomplete_frame = Some(vec![pins]);
+        let (standing, _) = self.rack();
+        if pins > standing {
+            return Err(Error::NotEnoughPinsLeft);
+        }
+
+        let mut rolls = self.incomplete_frame.take().unwrap_or_default();
+        rolls.push(pins);
+
+        match self.finish_frame(&rolls) {
+            Some(frame) => self.frames.push(frame),
+            None => self.incomplete_frame = Some(rolls),
         }
 
+        self.rolls.push(pins);
+        self.leaves.push(None);
         Ok(())
     }
 
+    /// Classifies the rolls of the current frame, or `None` if the bowler isn't done with it.
+    fn finish_frame(&self, rolls: &[u16]) -> Option<Frame> {
+        let pins = self.rules.pins();
+        let balls = self.rules.balls_per_frame();
+        let cleared_by = cleared_by(rolls, pins);
+
+        // the last frame is special
+        if self.frames.len() + 1 == self.rules.frames() {
+            // If someone clears the rack early then they get fill balls.
+            let needed = cleared_by.map_or(balls, |ball| ball + bonus_rolls(ball));
+            return (rolls.len() == needed).then(|| Frame::Last(rolls.to_vec()));
+        }
+
+        match cleared_by {
+            Some(1) => Some(Frame::Strike),
+            Some(2) => Some(Frame::Spare(rolls[0], rolls[1])),
+            Some(_) => Some(Frame::Cleared(rolls.to_vec())),
+            None if rolls.len() == balls => Some(Frame::Open(rolls.to_vec())),
+            None => None,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.frames.len() == self.rules.frames()
+    }
+
     pub fn score(&self) -> Option<u16> {
-        if self.frames.len() < 10 {
+        if !self.is_complete() {
             return None;
         }
 
-        let mut score = 0;
-
-        for (i, frame) in self.frames.iter().enumerate() {
-            match frame {
-                Frame::Last(a, b, c) => {
-                    let c = match c {
-                        Some(c) => c,
-                        None => &0,
-                    };
-                    score += a + b + c;
-                }
-                Frame::Open(a, b) => {
-                    score += a + b;
-                }
-                Frame::Spare(a, b) => {
-                    score += a + b;
-                    let next_roll = match self.frames.get(i + 1) {
-                        Some(Frame::Open(a, _)) => a,
-                        Some(Frame::Spare(a, _)) => a,
-                        Some(Frame::Strike) => &10,
-                        Some(Frame::Last(a, _, _)) => a,
-                        _ => &0,
-                    };
-                    score += next_roll;
-                }
-                Frame::Strike => {
-                    score += 10;
-                    let (a, b) = match self.frames.get(i + 1) {
-                        Some(Frame::Open(a, b)) => (a, b),
-                        Some(Frame::Spare(a, b)) => (a, b),
-                        Some(Frame::Last(a, b, _)) => (a, b),
-                        Some(Frame::Strike) => {
-                            let a = &10;
-                            let b = match self.frames.get(i + 2) {
-                                Some(Frame::Open(b, _)) => b,
-                                Some(Frame::Spare(b, _)) => b,
-                                Some(Frame::Last(b, _, _)) => b,
-                                Some(Frame::Strike) => &10,
-                                _ => &0,
-                            };
-                            (a, b)
-                        }
-                        _ => (&0, &0),
-                    };
-                    score += a + b;
-                }
+        self.frame_scores()
+            .last()
+            .and_then(|frame| frame.cumulative)
+    }
+
+    /// Pins still standing and balls already thrown at them in the current rack.
+    fn rack(&self) -> (u16, usize) {
+        let mut standing = self.rules.pins();
+        let mut thrown = 0;
+
+        for &pins in self.incomplete_frame.iter().flatten() {
+            if pins == standing {
+                standing = self.rules.pins();
+                thrown = 0;
+            } else {
+                standing -= pins;
+                thrown += 1;
             }
         }
 
-        Some(score)
+        (standing, thrown)
+    }
+
+    /// The total of every frame whose score is already known.
+    pub fn running_score(&self) -> u16 {
+        self.frame_scores()
+            .iter()
+            .rev()
+            .find_map(|frame| frame.cumulative)
+            .unwrap_or(0)
+    }
+
+    pub fn frame_scores(&self) -> Vec<FrameScore> {
+        let pins = self.rules.pins();
+        let mut scores = vec![];
+        let mut index = 0;
+        let mut cumulative = Some(0);
+
+        for frame in &self.frames {
+            let frame_rolls = frame.rolls(pins);
+            let next = index + frame_rolls.len();
+            let bonus: Option<u16> = self
+                .rolls
+                .get(next..next + frame.bonus_rolls())
+                .map(|bonus| bonus.iter().sum());
+
+            cumulative = cumulative
+                .zip(bonus)
+                .map(|(total, bonus)| total + frame_rolls.iter().sum::<u16>() + bonus);
+
+            scores.push(FrameScore {
+                pins,
+                rolls: frame_rolls,
+                state: frame.state(pins),
+                cumulative,
+            });
+            index = next;
+        }
+
+        if let Some(rolls) = &self.incomplete_frame {
+            scores.push(FrameScore {
+                pins,
+                rolls: rolls.clone(),
+                state: FrameState::InProgress,
+                cumulative: None,
+            });
+        }
+
+        scores
     }
 }
 
@@ -0,0 +1,68 @@
+use crate::{BowlingGame, Error, NotationError, Rules};
+use std::str::FromStr;
+
+impl BowlingGame {
+    /// Reads scorecard notation for a game played under the given rules.
+    pub fn parse_with_rules(s: &str, rules: Rules) -> Result<Self, Error> {
+        let mut game = BowlingGame::with_rules(rules);
+        // frame the current group of symbols started in, `None` between groups
+        let mut group_frame = None;
+        let mut unfinished = false;
+
+        for (position, symbol) in s.chars().enumerate() {
+            let error = |reason| Error::InvalidNotation { position, reason };
+
+            if symbol.is_whitespace() {
+                if let Some(frame) = group_frame.take() {
+                    unfinished = game.frames.len() == frame;
+                }
+                continue;
+            }
+
+            if game.is_complete() {
+                return Err(error(NotationError::GameComplete));
+            }
+
+            match group_frame {
+                None if unfinished => return Err(error(NotationError::FrameNotFinished)),
+                None => group_frame = Some(game.frames.len()),
+                Some(frame) if frame != game.frames.len() => {
+                    return Err(error(NotationError::MissingSeparator))
+                }
+                Some(_) => {}
+            }
+
+            let pins = game.pins_for_symbol(symbol).map_err(error)?;
+            game.roll(pins).map_err(|e| match e {
+                Error::NotEnoughPinsLeft => error(NotationError::NotEnoughPinsLeft),
+                _ => error(NotationError::GameComplete),
+            })?;
+        }
+
+        Ok(game)
+    }
+
+    fn pins_for_symbol(&self, symbol: char) -> Result<u16, NotationError> {
+        let (standing, thrown) = self.rack();
+
+        match symbol {
+            'X' if thrown == 0 => Ok(standing),
+            'X' => Err(NotationError::StrikeAfterFirstBall),
+            '/' if thrown == 0 => Err(NotationError::SpareOnFirstBall),
+            '/' => Ok(standing),
+            '-' => Ok(0),
+            '1'..='9' => Ok(symbol.to_digit(10).unwrap() as u16),
+            _ => Err(NotationError::UnexpectedSymbol(symbol)),
+        }
+    }
+}
+
+/// Reads scorecard notation such as `X 7/ 9- X -8 8/ -6 X X X81`, one
+/// whitespace-separated group per frame. The last frame may be unfinished.
+impl FromStr for BowlingGame {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_rules(s, Rules::default())
+    }
+}
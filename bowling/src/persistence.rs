@@ -0,0 +1,111 @@
+use crate::{BowlingGame, PinSet, Rules};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct RulesRecord {
+    frames: usize,
+    pins: u16,
+    balls_per_frame: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct GameRecord {
+    rules: RulesRecord,
+    frames: Vec<Vec<u16>>,
+    incomplete_frame: Option<Vec<u16>>,
+    /// Pin masks left standing after each roll, only when some were rolled pin by pin.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    leaves: Vec<Option<u16>>,
+}
+
+impl Serialize for BowlingGame {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let pins = self.rules.pins();
+        GameRecord {
+            rules: RulesRecord {
+                frames: self.rules.frames(),
+                pins,
+                balls_per_frame: self.rules.balls_per_frame(),
+            },
+            frames: self.frames.iter().map(|frame| frame.rolls(pins)).collect(),
+            incomplete_frame: self.incomplete_frame.clone(),
+            leaves: if self.leaves.iter().any(Option::is_some) {
+                self.leaves
+                    .iter()
+                    .map(|leave| leave.map(|pins| pins.mask()))
+                    .collect()
+            } else {
+                vec![]
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Replays every recorded roll, so a game `roll` would have rejected never comes back.
+impl<'de> Deserialize<'de> for BowlingGame {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let record = GameRecord::deserialize(deserializer)?;
+
+        let RulesRecord {
+            frames,
+            pins,
+            balls_per_frame,
+        } = record.rules;
+        if frames == 0 || !(1..=10).contains(&pins) || balls_per_frame == 0 {
+            return Err(D::Error::custom("invalid rules"));
+        }
+        let rules = Rules::ten_pin()
+            .with_frames(frames)
+            .with_pins(pins)
+            .with_balls_per_frame(balls_per_frame);
+
+        let rolls = record.frames.iter().flatten().count()
+            + record.incomplete_frame.iter().flatten().count();
+        if !record.leaves.is_empty() && record.leaves.len() != rolls {
+            return Err(D::Error::custom("leaves don't match the rolls"));
+        }
+
+        let mut game = BowlingGame::with_rules(rules);
+        let roll = |game: &mut BowlingGame, pins: u16| match record
+            .leaves
+            .get(game.rolls.len())
+            .copied()
+            .flatten()
+        {
+            Some(mask) => {
+                let standing = PinSet::new(mask).ok_or(format!("invalid leave {}", mask))?;
+                game.roll_standing(standing)
+                    .map_err(|e| format!("{:?}", e))?;
+                if game.rolls.last() != Some(&pins) {
+                    return Err(format!("leave {} doesn't match the roll", standing));
+                }
+                Ok(())
+            }
+            None => game.roll(pins).map_err(|e| format!("{:?}", e)),
+        };
+
+        for (i, frame) in record.frames.iter().enumerate() {
+            for &pins in frame {
+                roll(&mut game, pins)
+                    .map_err(|e| D::Error::custom(format!("frame {}: {}", i + 1, e)))?;
+            }
+            if game.frames.len() != i + 1 || game.incomplete_frame.is_some() {
+                return Err(D::Error::custom(format!("frame {} is not complete", i + 1)));
+            }
+        }
+
+        if let Some(rolls) = &record.incomplete_frame {
+            for &pins in rolls {
+                roll(&mut game, pins)
+                    .map_err(|e| D::Error::custom(format!("incomplete frame: {}", e)))?;
+            }
+            if game.incomplete_frame.as_ref() != Some(rolls) {
+                return Err(D::Error::custom("incomplete frame is complete"));
+            }
+        }
+
+        Ok(game)
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{BowlingGame, Error, FrameState};
+use std::fmt::Display;
+
+/// A set of pins, bit `n - 1` standing for pin `n`:
+///
+/// ```text
+/// 7 8 9 10
+///  4 5 6
+///   2 3
+///    1
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PinSet(u16);
+
+/// Column and row of every pin, columns in half-pin steps.
+const POSITIONS: [(i8, i8); 10] = [
+    (0, 0),
+    (-1, 1),
+    (1, 1),
+    (-2, 2),
+    (0, 2),
+    (2, 2),
+    (-3, 3),
+    (-1, 3),
+    (1, 3),
+    (3, 3),
+];
+
+const NAMES: [(&[u8], &str); 13] = [
+    (&[7, 10], "bedposts"),
+    (&[4, 6, 7, 10], "big four"),
+    (&[4, 6, 7, 8, 10], "Greek church"),
+    (&[4, 6, 7, 9, 10], "Greek church"),
+    (&[2, 7], "baby split"),
+    (&[3, 10], "baby split"),
+    (&[5, 7, 10], "lily"),
+    (&[2, 7, 10], "Christmas tree"),
+    (&[3, 7, 10], "Christmas tree"),
+    (&[2, 4, 5, 8], "bucket"),
+    (&[3, 5, 6, 9], "bucket"),
+    (&[1, 2, 4, 10], "washout"),
+    (&[1, 3, 6, 7], "washout"),
+];
+
+impl PinSet {
+    pub const EMPTY: PinSet = PinSet(0);
+    pub const FULL: PinSet = PinSet(0b11_1111_1111);
+
+    /// `None` if the mask has bits set beyond the tenth pin.
+    pub fn new(mask: u16) -> Option<Self> {
+        (mask & !Self::FULL.0 == 0).then_some(Self(mask))
+    }
+
+    /// `None` unless every pin is numbered 1 to 10.
+    pub fn from_pins(pins: &[u8]) -> Option<Self> {
+        pins.iter().try_fold(Self::EMPTY, |set, &pin| {
+            (1..=10)
+                .contains(&pin)
+                .then(|| Self(set.0 | 1 << (pin - 1)))
+        })
+    }
+
+    pub fn mask(&self) -> u16 {
+        self.0
+    }
+
+    pub fn len(&self) -> u16 {
+        self.0.count_ones() as u16
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(&self, pin: u8) -> bool {
+        (1..=10).contains(&pin) && self.0 & 1 << (pin - 1) != 0
+    }
+
+    pub fn is_subset(&self, other: &PinSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub fn pins(&self) -> Vec<u8> {
+        (1..=10).filter(|&pin| self.contains(pin)).collect()
+    }
+
+    /// A split as the USBC defines it: the headpin is down and a pin is down
+    /// either between standing pins or immediately ahead of two of them.
+    pub fn is_split(&self) -> bool {
+        if self.contains(1) || self.len() < 2 {
+            return false;
+        }
+
+        let pins = self.pins();
+        let gap_ahead = (1..=10).any(|pin| {
+            let (x, row) = POSITIONS[usize::from(pin - 1)];
+            !self.contains(pin)
+                && self.contains_at(x - 1, row + 1)
+                && self.contains_at(x + 1, row + 1)
+        });
+
+        gap_ahead || groups(&pins) > 1
+    }
+
+    /// The nickname of a well known leave, e.g. "bedposts" for the 7-10.
+    pub fn name(&self) -> Option<&'static str> {
+        NAMES
+            .iter()
+            .find(|(pins, _)| PinSet::from_pins(pins) == Some(*self))
+            .map(|(_, name)| *name)
+    }
+
+    fn contains_at(&self, x: i8, row: i8) -> bool {
+        POSITIONS
+            .iter()
+            .position(|&position| position == (x, row))
+            .is_some_and(|i| self.contains(i as u8 + 1))
+    }
+}
+
+/// Pins touching each other, or hidden one right behind the other, count as one group.
+fn groups(pins: &[u8]) -> usize {
+    let touching = |a: u8, b: u8| {
+        let (ax, arow) = POSITIONS[usize::from(a - 1)];
+        let (bx, brow) = POSITIONS[usize::from(b - 1)];
+        let (dx, drow) = ((ax - bx).abs(), (arow - brow).abs());
+        matches!((dx, drow), (2, 0) | (1, 1) | (0, 2))
+    };
+
+    let mut group_of: Vec<usize> = (0..pins.len()).collect();
+    for i in 0..pins.len() {
+        for j in 0..i {
+            if touching(pins[i], pins[j]) {
+                let (from, to) = (group_of[i], group_of[j]);
+                group_of
+                    .iter_mut()
+                    .filter(|g| **g == from)
+                    .for_each(|g| *g = to);
+            }
+        }
+    }
+
+    group_of.sort_unstable();
+    group_of.dedup();
+    group_of.len()
+}
+
+/// Pin numbers joined with dashes, e.g. `4-6-7-10`.
+impl Display for PinSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pins: Vec<String> = self.pins().iter().map(u8::to_string).collect();
+        write!(f, "{}", pins.join("-"))
+    }
+}
+
+impl BowlingGame {
+    /// Rolls by reporting which pins are still standing afterwards. Scores exactly
+    /// like `roll` with the number of pins that went down.
+    pub fn roll_standing(&mut self, standing: PinSet) -> Result<(), Error> {
+        if self.rules.pins() != 10 {
+            return Err(Error::PinTrackingUnsupported);
+        }
+        let before = self.standing_pins().ok_or(Error::StandingPinsUnknown)?;
+        if !standing.is_subset(&before) {
+            return Err(Error::PinsNotStanding);
+        }
+
+        self.roll(before.len() - standing.len())?;
+        *self.leaves.last_mut().unwrap() = Some(standing);
+        Ok(())
+    }
+
+    /// Pins standing in the current rack, if they're known pin by pin.
+    pub fn standing_pins(&self) -> Option<PinSet> {
+        if self.rules.pins() != 10 {
+            return None;
+        }
+        match self.rack() {
+            (_, 0) => Some(PinSet::FULL),
+            _ => *self.leaves.last()?,
+        }
+    }
+
+    /// What the first ball of each frame left standing, if it was rolled with `roll_standing`.
+    pub fn first_ball_leaves(&self) -> Vec<Option<PinSet>> {
+        let mut index = 0;
+        self.frame_scores()
+            .iter()
+            .map(|frame| {
+                let leave = self.leaves[index];
+                index += frame.rolls.len();
+                leave
+            })
+            .collect()
+    }
+
+    /// First-ball leaves that were picked up for a spare.
+    pub fn converted_leaves(&self) -> Vec<PinSet> {
+        self.first_ball_leaves()
+            .into_iter()
+            .zip(self.frame_scores())
+            .filter(|(_, frame)| frame.state == FrameState::Spare)
+            .filter_map(|(leave, _)| leave)
+            .collect()
+    }
+}
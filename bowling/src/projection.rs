@@ -0,0 +1,49 @@
+use crate::BowlingGame;
+
+impl BowlingGame {
+    /// The final score if every remaining ball knocks down all the pins left standing.
+    pub fn max_possible_score(&self) -> u16 {
+        self.finish_with(|standing| standing)
+    }
+
+    /// The final score if every remaining ball misses.
+    pub fn min_possible_score(&self) -> u16 {
+        self.finish_with(|_| 0)
+    }
+
+    fn finish_with(&self, pins: impl Fn(u16) -> u16) -> u16 {
+        let mut game = self.clone();
+        while !game.is_complete() {
+            let (standing, _) = game.rack();
+            game.roll(pins(standing))
+                .expect("never more pins than are standing");
+        }
+        game.score().unwrap_or(0)
+    }
+
+    /// The fewest pins each remaining ball can knock down while still finishing on
+    /// `target` or more, or `None` if the target is already out of reach.
+    pub fn rolls_needed(&self, target: u16) -> Option<Vec<u16>> {
+        if self.max_possible_score() < target {
+            return None;
+        }
+
+        let mut game = self.clone();
+        let mut needed = vec![];
+        while !game.is_complete() {
+            let (standing, _) = game.rack();
+            let (pins, next) = (0..=standing)
+                .map(|pins| {
+                    let mut next = game.clone();
+                    next.roll(pins).expect("never more pins than are standing");
+                    (pins, next)
+                })
+                .find(|(_, next)| next.max_possible_score() >= target)
+                .expect("knocking down every pin keeps the target reachable");
+            needed.push(pins);
+            game = next;
+        }
+
+        Some(needed)
+    }
+}
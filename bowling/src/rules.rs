@@ -0,0 +1,68 @@
+/// How a game is played: number of frames, pins per rack and balls per frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    frames: usize,
+    pins: u16,
+    balls_per_frame: usize,
+}
+
+impl Rules {
+    pub fn ten_pin() -> Self {
+        Self {
+            frames: 10,
+            pins: 10,
+            balls_per_frame: 2,
+        }
+    }
+
+    pub fn nine_pin() -> Self {
+        Self::ten_pin().with_pins(9)
+    }
+
+    pub fn candlepin() -> Self {
+        Self::ten_pin().with_balls_per_frame(3)
+    }
+
+    pub fn duckpin() -> Self {
+        Self::ten_pin().with_balls_per_frame(3)
+    }
+
+    /// Panics if `frames` is zero.
+    pub fn with_frames(&self, frames: usize) -> Self {
+        assert!(frames > 0, "a game needs at least one frame");
+        Self { frames, ..*self }
+    }
+
+    /// Panics unless `pins` is between 1 and 10.
+    pub fn with_pins(&self, pins: u16) -> Self {
+        assert!((1..=10).contains(&pins), "a rack holds 1 to 10 pins");
+        Self { pins, ..*self }
+    }
+
+    /// Panics if `balls_per_frame` is zero.
+    pub fn with_balls_per_frame(&self, balls_per_frame: usize) -> Self {
+        assert!(balls_per_frame > 0, "a frame needs at least one ball");
+        Self {
+            balls_per_frame,
+            ..*self
+        }
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn pins(&self) -> u16 {
+        self.pins
+    }
+
+    pub fn balls_per_frame(&self) -> usize {
+        self.balls_per_frame
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::ten_pin()
+    }
+}
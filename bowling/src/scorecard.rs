@@ -0,0 +1,58 @@
+use crate::{BowlingGame, FrameScore, Rules};
+use std::fmt::Display;
+
+impl FrameScore {
+    /// The marks written in this frame's box, e.g. `X`, `7/`, `9-` or `X81`.
+    pub fn notation(&self) -> String {
+        let mut notation = String::new();
+        let mut standing = self.pins;
+
+        for &pins in &self.rolls {
+            if pins == standing {
+                notation.push(if standing == self.pins { 'X' } else { '/' });
+                standing = self.pins;
+            } else {
+                notation.push(match pins {
+                    0 => '-',
+                    pins => char::from_digit(pins.into(), 10).unwrap(),
+                });
+                standing -= pins;
+            }
+        }
+
+        notation
+    }
+}
+
+/// Box width that fits the most marks a frame can hold and the highest possible total,
+/// so every column lines up whatever the rules.
+fn column_width(rules: Rules) -> usize {
+    // the last frame can take up to three balls after an early strike
+    let marks = rules.balls_per_frame().max(3);
+    let best_game = rules.frames() * usize::from(rules.pins()) * 3;
+    marks.max(best_game.to_string().len())
+}
+
+/// Renders the two-row paper scorecard: marks on top, running totals below.
+impl Display for BowlingGame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let frames = self.frame_scores();
+        let width = column_width(self.rules);
+
+        for i in 0..self.rules.frames() {
+            let marks = frames.get(i).map(FrameScore::notation).unwrap_or_default();
+            write!(f, "| {:<width$} ", marks)?;
+        }
+        writeln!(f, "|")?;
+
+        for i in 0..self.rules.frames() {
+            let total = frames
+                .get(i)
+                .and_then(|frame| frame.cumulative)
+                .map(|total| total.to_string())
+                .unwrap_or_default();
+            write!(f, "| {:>width$} ", total)?;
+        }
+        write!(f, "|")
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{BowlingGame, FrameState};
+use std::collections::HashMap;
+
+/// Totals over a bowler's completed games.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BowlerStats {
+    pub games: usize,
+    pub frames: usize,
+    pub strikes: usize,
+    pub spares: usize,
+    pub open_frames: usize,
+    pub first_ball_pins: u32,
+    pub clean_games: usize,
+    pub splits: usize,
+    pub splits_converted: usize,
+    /// Games rolled pin by pin without a single split.
+    pub split_free_games: usize,
+}
+
+impl BowlerStats {
+    /// Stats over every complete game, unfinished ones are skipped.
+    pub fn from_games<'a>(games: impl IntoIterator<Item = &'a BowlingGame>) -> Self {
+        let mut stats = Self::default();
+        for game in games {
+            stats.add_game(game);
+        }
+        stats
+    }
+
+    fn add_game(&mut self, game: &BowlingGame) {
+        if !game.is_complete() {
+            return;
+        }
+
+        let frames = game.frame_scores();
+        self.games += 1;
+        self.frames += frames.len();
+
+        for frame in &frames {
+            match frame.state {
+                FrameState::Strike => self.strikes += 1,
+                FrameState::Spare => self.spares += 1,
+                FrameState::Open => self.open_frames += 1,
+                FrameState::Cleared | FrameState::InProgress => {}
+            }
+            self.first_ball_pins += u32::from(frame.rolls[0]);
+        }
+
+        if !frames.iter().any(|frame| frame.state == FrameState::Open) {
+            self.clean_games += 1;
+        }
+
+        let leaves = game.first_ball_leaves();
+        let mut splits = 0;
+        for (leave, frame) in leaves.iter().zip(&frames) {
+            if leave.is_some_and(|leave| leave.is_split()) {
+                splits += 1;
+                if frame.state == FrameState::Spare {
+                    self.splits_converted += 1;
+                }
+            }
+        }
+        self.splits += splits;
+        if splits == 0 && leaves.iter().all(Option::is_some) {
+            self.split_free_games += 1;
+        }
+    }
+
+    pub fn strike_percentage(&self) -> f64 {
+        percentage(self.strikes, self.frames)
+    }
+
+    /// Spares as a share of the frames that weren't strikes.
+    pub fn spare_conversion_percentage(&self) -> f64 {
+        percentage(self.spares, self.frames - self.strikes)
+    }
+
+    pub fn open_frame_percentage(&self) -> f64 {
+        percentage(self.open_frames, self.frames)
+    }
+
+    pub fn first_ball_average(&self) -> f64 {
+        if self.frames == 0 {
+            return 0.0;
+        }
+        f64::from(self.first_ball_pins) / self.frames as f64
+    }
+}
+
+fn percentage(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    count as f64 * 100.0 / total as f64
+}
+
+/// Groups games by bowler name and works out each bowler's stats.
+pub fn stats_by_bowler<'a>(
+    games: impl IntoIterator<Item = (&'a str, &'a BowlingGame)>,
+) -> HashMap<String, BowlerStats> {
+    let mut stats: HashMap<String, BowlerStats> = HashMap::new();
+    for (bowler, game) in games {
+        stats.entry(bowler.to_string()).or_default().add_game(game);
+    }
+    stats
+}
@@ -0,0 +1,110 @@
+use bowling::*;
+
+fn game_with(rolls: &[u16]) -> BowlingGame {
+    let mut game = BowlingGame::new();
+    for &pins in rolls {
+        game.roll(pins).unwrap();
+    }
+    game
+}
+
+#[test]
+fn a_new_game_has_no_frames() {
+    let game = BowlingGame::new();
+
+    assert!(game.frame_scores().is_empty());
+    assert_eq!(game.running_score(), 0);
+}
+
+#[test]
+fn open_frames_are_scored_immediately() {
+    let game = game_with(&[3, 6, 4, 2]);
+
+    assert_eq!(
+        game.frame_scores(),
+        vec![
+            FrameScore {
+                pins: 10,
+                rolls: vec![3, 6],
+                state: FrameState::Open,
+                cumulative: Some(9),
+            },
+            FrameScore {
+                pins: 10,
+                rolls: vec![4, 2],
+                state: FrameState::Open,
+                cumulative: Some(15),
+            },
+        ]
+    );
+    assert_eq!(game.running_score(), 15);
+}
+
+#[test]
+fn an_unfinished_frame_is_in_progress() {
+    let game = game_with(&[3, 6, 4]);
+    let frames = game.frame_scores();
+
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[1].rolls, vec![4]);
+    assert_eq!(frames[1].state, FrameState::InProgress);
+    assert_eq!(frames[1].cumulative, None);
+    assert_eq!(game.running_score(), 9);
+}
+
+#[test]
+fn a_strike_waits_for_two_bonus_rolls() {
+    let game = game_with(&[10, 10]);
+    let frames = game.frame_scores();
+
+    assert!(frames.iter().all(FrameScore::is_pending_bonus));
+    assert_eq!(game.running_score(), 0);
+
+    let game = game_with(&[10, 10, 4]);
+    let frames = game.frame_scores();
+
+    assert_eq!(frames[0].cumulative, Some(24));
+    assert!(frames[1].is_pending_bonus());
+    assert_eq!(frames[2].state, FrameState::InProgress);
+    assert_eq!(game.running_score(), 24);
+}
+
+#[test]
+fn a_spare_waits_for_one_bonus_roll() {
+    let game = game_with(&[7, 3]);
+
+    assert!(game.frame_scores()[0].is_pending_bonus());
+
+    let game = game_with(&[7, 3, 5]);
+
+    assert_eq!(game.frame_scores()[0].cumulative, Some(15));
+    assert_eq!(game.running_score(), 15);
+}
+
+#[test]
+fn the_tenth_frame_includes_its_fill_balls() {
+    let mut rolls = vec![0; 18];
+    rolls.extend([10, 7, 3]);
+    let game = game_with(&rolls);
+    let last = game.frame_scores().pop().unwrap();
+
+    assert_eq!(last.rolls, vec![10, 7, 3]);
+    assert_eq!(last.state, FrameState::Strike);
+    assert_eq!(last.cumulative, Some(20));
+    assert_eq!(game.score(), Some(20));
+}
+
+#[test]
+fn running_score_of_a_perfect_game() {
+    let game = game_with(&[10; 12]);
+    let cumulative: Vec<_> = game
+        .frame_scores()
+        .iter()
+        .map(|frame| frame.cumulative.unwrap())
+        .collect();
+
+    assert_eq!(
+        cumulative,
+        vec![30, 60, 90, 120, 150, 180, 210, 240, 270, 300]
+    );
+}
@@ -0,0 +1,108 @@
+use bowling::*;
+
+#[test]
+fn rolls_are_recorded_in_order() {
+    let game: BowlingGame = "X 7/ 9-".parse().unwrap();
+
+    assert_eq!(game.rolls(), &[10, 7, 3, 9, 0]);
+}
+
+#[test]
+fn rejected_rolls_are_not_recorded() {
+    let mut game = BowlingGame::new();
+    game.roll(5).unwrap();
+
+    assert_eq!(game.roll(6), Err(Error::NotEnoughPinsLeft));
+    assert_eq!(game.rolls(), &[5]);
+    assert_eq!(game.frame_scores()[0].rolls, vec![5]);
+
+    game.roll(5).unwrap();
+    assert_eq!(game.frame_scores()[0].state, FrameState::Spare);
+}
+
+#[test]
+fn a_rejected_fill_ball_keeps_the_tenth_frame() {
+    let mut game: BowlingGame = "-- -- -- -- -- -- -- -- -- X".parse().unwrap();
+    game.roll(5).unwrap();
+
+    assert_eq!(game.roll(6), Err(Error::NotEnoughPinsLeft));
+    assert!(game.roll(5).is_ok());
+    assert_eq!(game.score(), Some(20));
+}
+
+#[test]
+fn a_game_can_be_rebuilt_from_its_rolls() {
+    let game: BowlingGame = "X 7/ 9- X -8 8/ -6 X X X81".parse().unwrap();
+    let replayed = BowlingGame::from_rolls(game.rolls()).unwrap();
+
+    assert_eq!(replayed.frame_scores(), game.frame_scores());
+    assert_eq!(replayed.score(), Some(167));
+}
+
+#[test]
+fn a_game_can_be_rebuilt_with_its_rules() {
+    let mut game = BowlingGame::with_rules(Rules::candlepin());
+    for pins in [3, 3, 4, 10, 6, 4, 5, 2, 1] {
+        game.roll(pins).unwrap();
+    }
+    let replayed = BowlingGame::from_rolls_with_rules(Rules::candlepin(), game.rolls()).unwrap();
+
+    assert_eq!(replayed.rules(), Rules::candlepin());
+    assert_eq!(replayed.frame_scores(), game.frame_scores());
+    assert_eq!(replayed.running_score(), 53);
+    assert_eq!(
+        BowlingGame::from_rolls(game.rolls()).err(),
+        Some(Error::NotEnoughPinsLeft)
+    );
+}
+
+#[test]
+fn replaying_applies_the_same_rules_as_rolling() {
+    assert_eq!(
+        BowlingGame::from_rolls(&[5, 6]).err(),
+        Some(Error::NotEnoughPinsLeft)
+    );
+    assert_eq!(
+        BowlingGame::from_rolls(&[0; 21]).err(),
+        Some(Error::GameComplete)
+    );
+
+    let mut fill_balls = vec![0; 18];
+    fill_balls.extend([10, 5, 6]);
+    assert_eq!(
+        BowlingGame::from_rolls(&fill_balls).err(),
+        Some(Error::NotEnoughPinsLeft)
+    );
+}
+
+#[test]
+fn undo_takes_back_the_last_roll() {
+    let mut game: BowlingGame = "X 7/ 9".parse().unwrap();
+
+    assert_eq!(game.undo(), Some(9));
+    assert_eq!(game.rolls(), &[10, 7, 3]);
+    assert!(game.frame_scores()[1].is_pending_bonus());
+
+    assert_eq!(game.undo(), Some(3));
+    assert_eq!(game.frame_scores()[1].state, FrameState::InProgress);
+    assert!(game.roll(2).is_ok());
+    assert_eq!(game.running_score(), 28);
+}
+
+#[test]
+fn undo_reopens_a_complete_game() {
+    let mut game: BowlingGame = "X X X X X X X X X XXX".parse().unwrap();
+
+    assert_eq!(game.undo(), Some(10));
+    assert_eq!(game.score(), None);
+    assert!(game.roll(9).is_ok());
+    assert_eq!(game.score(), Some(299));
+}
+
+#[test]
+fn undo_on_a_new_game_does_nothing() {
+    let mut game = BowlingGame::new();
+
+    assert_eq!(game.undo(), None);
+    assert!(game.rolls().is_empty());
+}
@@ -0,0 +1,181 @@
+use bowling::*;
+
+fn bowl_frame(game: &mut Match, bowler: &str, rolls: &[u16]) {
+    for &pins in rolls {
+        game.roll(bowler, pins).unwrap();
+    }
+}
+
+#[test]
+fn bowlers_take_turns_frame_by_frame() {
+    let mut game = Match::new(&["Ann", "Bob"]).unwrap();
+
+    assert_eq!(game.current_bowler(), Some("Ann"));
+    game.roll("Ann", 3).unwrap();
+    assert_eq!(game.current_bowler(), Some("Ann"));
+    game.roll("Ann", 4).unwrap();
+    assert_eq!(game.current_bowler(), Some("Bob"));
+    game.roll("Bob", 10).unwrap();
+    assert_eq!(game.current_bowler(), Some("Ann"));
+}
+
+#[test]
+fn rolling_out_of_turn_is_an_error() {
+    let mut game = Match::new(&["Ann", "Bob"]).unwrap();
+
+    assert_eq!(game.roll("Bob", 3), Err(Error::NotYourTurn));
+    assert_eq!(game.roll("Cid", 3), Err(Error::UnknownBowler));
+    assert_eq!(game.roll("Ann", 11), Err(Error::NotEnoughPinsLeft));
+    assert_eq!(game.current_bowler(), Some("Ann"));
+}
+
+#[test]
+fn a_match_is_complete_when_every_game_is() {
+    let mut game = Match::new(&["Ann", "Bob"]).unwrap();
+
+    for _ in 0..9 {
+        bowl_frame(&mut game, "Ann", &[10]);
+        bowl_frame(&mut game, "Bob", &[9, 0]);
+    }
+    bowl_frame(&mut game, "Ann", &[10, 10, 10]);
+    assert!(!game.is_complete());
+    bowl_frame(&mut game, "Bob", &[9, 0]);
+
+    assert!(game.is_complete());
+    assert_eq!(game.current_bowler(), None);
+    assert_eq!(game.roll("Ann", 0), Err(Error::GameComplete));
+    assert_eq!(game.game("Ann").unwrap().score(), Some(300));
+    assert_eq!(game.standings(), vec![("Ann", 300), ("Bob", 90)]);
+}
+
+#[test]
+fn match_standings_use_running_scores() {
+    let mut game = Match::new(&["Ann", "Bob"]).unwrap();
+    bowl_frame(&mut game, "Ann", &[3, 4]);
+    bowl_frame(&mut game, "Bob", &[5, 4]);
+    bowl_frame(&mut game, "Ann", &[10]);
+
+    assert_eq!(game.standings(), vec![("Bob", 9), ("Ann", 7)]);
+}
+
+#[test]
+fn handicap_is_a_percentage_of_the_gap_to_the_base() {
+    let handicap = Handicap::new(220, 90);
+
+    assert_eq!(handicap.for_average(150), 63);
+    assert_eq!(handicap.for_average(220), 0);
+    assert_eq!(handicap.for_average(240), 0);
+}
+
+fn bowl_series(series: &mut Series, scores: &[(&str, u16)]) {
+    // every bowler rolls `pins` with each first ball and misses the second
+    for _ in 0..10 {
+        for &(bowler, pins) in scores {
+            series.roll(bowler, pins).unwrap();
+            series.roll(bowler, 0).unwrap();
+        }
+    }
+}
+
+#[test]
+fn a_series_is_three_games_by_default() {
+    let mut series = Series::new(&["Ann", "Bob"]).unwrap();
+
+    bowl_series(&mut series, &[("Ann", 9), ("Bob", 5)]);
+    bowl_series(&mut series, &[("Ann", 8), ("Bob", 7)]);
+    assert!(!series.is_complete());
+    bowl_series(&mut series, &[("Ann", 1), ("Bob", 6)]);
+
+    assert!(series.is_complete());
+    assert_eq!(series.matches().len(), 3);
+    assert_eq!(series.current_bowler(), None);
+    assert_eq!(series.roll("Ann", 0), Err(Error::GameComplete));
+    assert_eq!(series.series_total("Ann"), Some(180));
+    assert_eq!(series.series_total("Bob"), Some(180));
+    assert_eq!(series.series_total("Cid"), None);
+    assert_eq!(series.high_game(), Some(("Ann", 90)));
+}
+
+#[test]
+fn series_standings_include_handicap_per_game() {
+    let mut series = Series::new(&["Ann", "Bob"])
+        .unwrap()
+        .with_games(2)
+        .with_handicap(Handicap::new(200, 100))
+        .with_average("Bob", 150);
+
+    bowl_series(&mut series, &[("Ann", 9), ("Bob", 5)]);
+    bowl_series(&mut series, &[("Ann", 9), ("Bob", 5)]);
+
+    assert_eq!(
+        series.standings(),
+        vec![
+            Standing {
+                bowler: "Bob".to_string(),
+                scratch: 100,
+                handicap: 100,
+                total: 200,
+            },
+            Standing {
+                bowler: "Ann".to_string(),
+                scratch: 180,
+                handicap: 0,
+                total: 180,
+            },
+        ]
+    );
+}
+
+#[test]
+fn a_series_enforces_turn_order_across_games() {
+    let mut series = Series::new(&["Ann", "Bob"]).unwrap().with_games(2);
+
+    assert_eq!(series.roll("Bob", 1), Err(Error::NotYourTurn));
+    assert_eq!(series.roll("Ann", 11), Err(Error::NotEnoughPinsLeft));
+    assert!(series.matches().is_empty());
+
+    bowl_series(&mut series, &[("Ann", 1), ("Bob", 1)]);
+    assert_eq!(series.current_bowler(), Some("Ann"));
+    assert_eq!(series.roll("Bob", 1), Err(Error::NotYourTurn));
+    assert!(series.roll("Ann", 1).is_ok());
+    assert_eq!(series.matches().len(), 2);
+}
+
+#[test]
+fn bowlers_need_distinct_names() {
+    assert_eq!(
+        Match::new(&["Ann", "Ann"]).err(),
+        Some(Error::DuplicateBowler)
+    );
+    assert_eq!(
+        Match::with_rules(&["Ann", "Bob", "Ann"], Rules::default()).err(),
+        Some(Error::DuplicateBowler)
+    );
+    assert_eq!(
+        Series::new(&["Ann", "Bob", "Bob"]).err(),
+        Some(Error::DuplicateBowler)
+    );
+}
+
+#[test]
+fn long_series_totals_do_not_overflow() {
+    let mut series = Series::new(&["Ann"])
+        .unwrap()
+        .with_games(400)
+        .with_handicap(Handicap::new(400, 100))
+        .with_average("Ann", 0);
+    while !series.is_complete() {
+        series.roll("Ann", 10).unwrap();
+    }
+
+    assert_eq!(series.series_total("Ann"), Some(120_000));
+    assert_eq!(
+        series.standings(),
+        vec![Standing {
+            bowler: "Ann".into(),
+            scratch: 120_000,
+            handicap: 160_000,
+            total: 280_000,
+        }]
+    );
+}
@@ -0,0 +1,112 @@
+use bowling::*;
+
+fn notation_error(position: usize, reason: NotationError) -> Error {
+    Error::InvalidNotation { position, reason }
+}
+
+#[test]
+fn parse_a_complete_game() {
+    let game: BowlingGame = "X 7/ 9- X -8 8/ -6 X X X81".parse().unwrap();
+
+    assert_eq!(game.score(), Some(167));
+    assert_eq!(
+        game.to_string(),
+        "| X   | 7/  | 9-  | X   | -8  | 8/  | -6  | X   | X   | X81 |\n\
+         |  20 |  39 |  48 |  66 |  74 |  84 |  90 | 120 | 148 | 167 |"
+    );
+}
+
+#[test]
+fn parse_a_perfect_game() {
+    let game: BowlingGame = "X X X X X X X X X XXX".parse().unwrap();
+
+    assert_eq!(game.score(), Some(300));
+}
+
+#[test]
+fn parse_a_game_in_progress() {
+    let game: BowlingGame = "  X 7/ 4".parse().unwrap();
+
+    assert_eq!(game.score(), None);
+    assert_eq!(game.running_score(), 34);
+}
+
+#[test]
+fn parse_an_empty_string() {
+    let game: BowlingGame = "".parse().unwrap();
+
+    assert!(game.frame_scores().is_empty());
+}
+
+#[test]
+fn notation_round_trips_through_frame_scores() {
+    let notation = "X 7/ 9- X -8 8/ -6 X X 6/X";
+    let game: BowlingGame = notation.parse().unwrap();
+    let frames: Vec<_> = game
+        .frame_scores()
+        .iter()
+        .map(FrameScore::notation)
+        .collect();
+
+    assert_eq!(frames.join(" "), notation);
+}
+
+#[test]
+fn unknown_symbols_are_rejected() {
+    assert_eq!(
+        "X 7/ 0-".parse::<BowlingGame>().err(),
+        Some(notation_error(5, NotationError::UnexpectedSymbol('0')))
+    );
+}
+
+#[test]
+fn a_spare_needs_a_first_ball() {
+    assert_eq!(
+        "X /".parse::<BowlingGame>().err(),
+        Some(notation_error(2, NotationError::SpareOnFirstBall))
+    );
+}
+
+#[test]
+fn a_strike_must_be_the_first_ball_of_a_rack() {
+    assert_eq!(
+        "-X".parse::<BowlingGame>().err(),
+        Some(notation_error(1, NotationError::StrikeAfterFirstBall))
+    );
+}
+
+#[test]
+fn a_frame_cannot_knock_down_more_than_ten_pins() {
+    assert_eq!(
+        "X 78".parse::<BowlingGame>().err(),
+        Some(notation_error(3, NotationError::NotEnoughPinsLeft))
+    );
+}
+
+#[test]
+fn frames_must_be_separated() {
+    assert_eq!(
+        "X7/".parse::<BowlingGame>().err(),
+        Some(notation_error(1, NotationError::MissingSeparator))
+    );
+}
+
+#[test]
+fn frames_must_not_be_split() {
+    assert_eq!(
+        "7 / X".parse::<BowlingGame>().err(),
+        Some(notation_error(2, NotationError::FrameNotFinished))
+    );
+}
+
+#[test]
+fn nothing_can_follow_a_complete_game() {
+    assert_eq!(
+        "X X X X X X X X X XXX X".parse::<BowlingGame>().err(),
+        Some(notation_error(22, NotationError::GameComplete))
+    );
+    assert_eq!(
+        "X X X X X X X X X XXXX".parse::<BowlingGame>().err(),
+        Some(notation_error(21, NotationError::GameComplete))
+    );
+}
@@ -0,0 +1,145 @@
+use bowling::*;
+
+fn pins(pins: &[u8]) -> PinSet {
+    PinSet::from_pins(pins).unwrap()
+}
+
+#[test]
+fn pin_sets_from_masks_and_numbers() {
+    assert_eq!(PinSet::new(0b10_0100_0000), Some(pins(&[7, 10])));
+    assert_eq!(PinSet::new(0b100_0000_0000), None);
+    assert_eq!(PinSet::from_pins(&[0]), None);
+    assert_eq!(PinSet::from_pins(&[11]), None);
+    assert_eq!(PinSet::FULL.len(), 10);
+    assert!(PinSet::EMPTY.is_empty());
+    assert_eq!(pins(&[10, 4, 7, 6]).to_string(), "4-6-7-10");
+}
+
+#[test]
+fn splits() {
+    for split in [
+        &[7, 10][..],
+        &[4, 6, 7, 10],
+        &[2, 7],
+        &[3, 10],
+        &[5, 7, 10],
+        &[4, 6],
+        &[7, 9],
+        &[6, 7],
+        &[4, 9],
+        &[5, 6],
+    ] {
+        assert!(pins(split).is_split(), "{:?} is a split", split);
+    }
+}
+
+#[test]
+fn leaves_that_are_not_splits() {
+    for leave in [
+        &[][..],
+        &[7],
+        &[2, 4, 5, 8],
+        &[2, 8],
+        &[3, 9],
+        &[6, 10],
+        &[1, 7, 10],
+        &[1, 2, 4, 10],
+    ] {
+        assert!(!pins(leave).is_split(), "{:?} isn't a split", leave);
+    }
+}
+
+#[test]
+fn named_leaves() {
+    assert_eq!(pins(&[7, 10]).name(), Some("bedposts"));
+    assert_eq!(pins(&[4, 6, 7, 10]).name(), Some("big four"));
+    assert_eq!(pins(&[4, 6, 7, 9, 10]).name(), Some("Greek church"));
+    assert_eq!(pins(&[6, 10]).name(), None);
+}
+
+#[test]
+fn rolling_by_pins_scores_like_rolling_by_count() {
+    let mut by_pins = BowlingGame::new();
+    by_pins.roll_standing(pins(&[7, 10])).unwrap();
+    by_pins.roll_standing(pins(&[10])).unwrap();
+    by_pins.roll_standing(PinSet::EMPTY).unwrap();
+    by_pins.roll_standing(pins(&[4, 6])).unwrap();
+    by_pins.roll_standing(PinSet::EMPTY).unwrap();
+
+    let by_count = BowlingGame::from_rolls(&[8, 1, 10, 8, 2]).unwrap();
+
+    assert_eq!(by_pins.rolls(), by_count.rolls());
+    assert_eq!(by_pins.frame_scores(), by_count.frame_scores());
+}
+
+#[test]
+fn leaves_and_conversions() {
+    let mut game = BowlingGame::new();
+    game.roll_standing(pins(&[7, 10])).unwrap();
+    game.roll_standing(pins(&[10])).unwrap();
+    game.roll_standing(PinSet::EMPTY).unwrap();
+    game.roll_standing(pins(&[4, 6])).unwrap();
+    game.roll_standing(PinSet::EMPTY).unwrap();
+    game.roll(7).unwrap();
+
+    assert_eq!(
+        game.first_ball_leaves(),
+        vec![
+            Some(pins(&[7, 10])),
+            Some(PinSet::EMPTY),
+            Some(pins(&[4, 6])),
+            None
+        ]
+    );
+    assert_eq!(game.converted_leaves(), vec![pins(&[4, 6])]);
+}
+
+#[test]
+fn standing_pins_must_have_been_standing() {
+    let mut game = BowlingGame::new();
+    game.roll_standing(pins(&[7, 10])).unwrap();
+
+    assert_eq!(game.standing_pins(), Some(pins(&[7, 10])));
+    assert_eq!(
+        game.roll_standing(pins(&[7, 9])),
+        Err(Error::PinsNotStanding)
+    );
+    assert!(game.roll_standing(pins(&[7])).is_ok());
+    assert_eq!(game.standing_pins(), Some(PinSet::FULL));
+}
+
+#[test]
+fn standing_pins_are_unknown_after_rolling_by_count() {
+    let mut game = BowlingGame::new();
+    game.roll(3).unwrap();
+
+    assert_eq!(game.standing_pins(), None);
+    assert_eq!(
+        game.roll_standing(PinSet::EMPTY),
+        Err(Error::StandingPinsUnknown)
+    );
+
+    game.roll(3).unwrap();
+    assert!(game.roll_standing(PinSet::EMPTY).is_ok());
+}
+
+#[test]
+fn rolling_by_pins_needs_ten_pins() {
+    let mut game = BowlingGame::with_rules(Rules::nine_pin());
+
+    assert_eq!(
+        game.roll_standing(PinSet::EMPTY),
+        Err(Error::PinTrackingUnsupported)
+    );
+    assert_eq!(game.standing_pins(), None);
+}
+
+#[test]
+fn undo_keeps_the_pins() {
+    let mut game = BowlingGame::new();
+    game.roll_standing(pins(&[7, 10])).unwrap();
+    game.roll_standing(pins(&[10])).unwrap();
+
+    assert_eq!(game.undo(), Some(1));
+    assert_eq!(game.standing_pins(), Some(pins(&[7, 10])));
+}
@@ -0,0 +1,66 @@
+use bowling::*;
+
+#[test]
+fn a_new_game_can_end_anywhere_from_zero_to_perfect() {
+    let game = BowlingGame::new();
+
+    assert_eq!(game.max_possible_score(), 300);
+    assert_eq!(game.min_possible_score(), 0);
+}
+
+#[test]
+fn projections_for_a_game_in_progress() {
+    let game: BowlingGame = "X 7/ 9- X -8 8/ -6 X".parse().unwrap();
+
+    assert_eq!(game.running_score(), 90);
+    assert_eq!(game.min_possible_score(), 100);
+    assert_eq!(game.max_possible_score(), 180);
+}
+
+#[test]
+fn a_spare_is_still_possible_mid_frame() {
+    let game: BowlingGame = "-- -- -- -- -- -- -- -- -- 7".parse().unwrap();
+
+    assert_eq!(game.max_possible_score(), 20);
+    assert_eq!(game.min_possible_score(), 7);
+}
+
+#[test]
+fn projections_of_a_finished_game_are_its_score() {
+    let game: BowlingGame = "X 7/ 9- X -8 8/ -6 X X X81".parse().unwrap();
+
+    assert_eq!(game.max_possible_score(), 167);
+    assert_eq!(game.min_possible_score(), 167);
+    assert_eq!(game.rolls_needed(167), Some(vec![]));
+    assert_eq!(game.rolls_needed(168), None);
+}
+
+#[test]
+fn projections_follow_the_rules() {
+    let game = BowlingGame::with_rules(Rules::nine_pin().with_frames(3));
+
+    assert_eq!(game.max_possible_score(), 81);
+}
+
+#[test]
+fn rolls_needed_to_reach_a_target() {
+    let game: BowlingGame = "X X X X X X X X".parse().unwrap();
+
+    assert_eq!(game.rolls_needed(300), Some(vec![10, 10, 10, 10]));
+    assert_eq!(game.rolls_needed(0), Some(vec![0, 0, 0, 0]));
+
+    let needed = game.rolls_needed(250).unwrap();
+    let mut finished = game.clone();
+    for pins in needed {
+        finished.roll(pins).unwrap();
+    }
+    assert!(finished.score().unwrap() >= 250);
+}
+
+#[test]
+fn rolls_needed_asks_for_as_little_as_possible_each_ball() {
+    let game: BowlingGame = "-- -- -- -- -- -- -- -- --".parse().unwrap();
+
+    assert_eq!(game.rolls_needed(20), Some(vec![0, 10, 10]));
+    assert_eq!(game.rolls_needed(31), None);
+}
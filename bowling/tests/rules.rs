@@ -0,0 +1,136 @@
+use bowling::*;
+
+fn game_with(rules: Rules, rolls: &[u16]) -> BowlingGame {
+    let mut game = BowlingGame::with_rules(rules);
+    for &pins in rolls {
+        game.roll(pins).unwrap();
+    }
+    game
+}
+
+#[test]
+fn the_default_rules_are_ten_pin() {
+    let rules = Rules::default();
+
+    assert_eq!(rules, Rules::ten_pin());
+    assert_eq!(rules.frames(), 10);
+    assert_eq!(rules.pins(), 10);
+    assert_eq!(rules.balls_per_frame(), 2);
+    assert_eq!(BowlingGame::new().rules(), rules);
+}
+
+#[test]
+fn a_short_practice_game() {
+    let rules = Rules::ten_pin().with_frames(3);
+    let mut game = game_with(rules, &[10, 10, 10, 10]);
+
+    assert_eq!(game.score(), None);
+    assert!(game.roll(10).is_ok());
+    assert_eq!(game.score(), Some(90));
+    assert_eq!(game.roll(0), Err(Error::GameComplete));
+}
+
+#[test]
+fn nine_pin_strikes_knock_down_nine() {
+    let mut game = BowlingGame::with_rules(Rules::nine_pin());
+
+    assert_eq!(game.roll(10), Err(Error::NotEnoughPinsLeft));
+    for _ in 0..12 {
+        game.roll(9).unwrap();
+    }
+    assert_eq!(game.score(), Some(270));
+}
+
+#[test]
+fn nine_pin_spares() {
+    let game = game_with(Rules::nine_pin(), &[4, 5, 3]);
+    let frames = game.frame_scores();
+
+    assert_eq!(frames[0].state, FrameState::Spare);
+    assert_eq!(frames[0].cumulative, Some(12));
+    assert_eq!(frames[0].notation(), "4/");
+}
+
+#[test]
+fn candlepin_frames_have_three_balls() {
+    let mut game = game_with(Rules::candlepin(), &[3, 3]);
+
+    assert_eq!(game.frame_scores()[0].state, FrameState::InProgress);
+    assert_eq!(game.roll(5), Err(Error::NotEnoughPinsLeft));
+    game.roll(3).unwrap();
+
+    let frame = &game.frame_scores()[0];
+    assert_eq!(frame.state, FrameState::Open);
+    assert_eq!(frame.cumulative, Some(9));
+    assert_eq!(frame.notation(), "333");
+}
+
+#[test]
+fn candlepin_ten_box_earns_no_bonus() {
+    let game = game_with(Rules::candlepin(), &[3, 3, 4, 5, 0, 0]);
+    let frames = game.frame_scores();
+
+    assert_eq!(frames[0].state, FrameState::Cleared);
+    assert_eq!(frames[0].cumulative, Some(10));
+    assert_eq!(frames[0].notation(), "33/");
+}
+
+#[test]
+fn candlepin_strikes_and_spares_earn_the_usual_bonus() {
+    let game = game_with(Rules::candlepin(), &[10, 6, 4, 5, 2, 1]);
+    let frames = game.frame_scores();
+
+    assert_eq!(frames[0].cumulative, Some(20));
+    assert_eq!(frames[1].cumulative, Some(35));
+    assert_eq!(frames[2].cumulative, Some(43));
+}
+
+#[test]
+fn candlepin_last_frame() {
+    let mut rolls = vec![0; 27];
+    rolls.extend([2, 2, 2]);
+    assert_eq!(game_with(Rules::candlepin(), &rolls).score(), Some(6));
+
+    let mut rolls = vec![0; 27];
+    rolls.extend([2, 8, 2]);
+    assert_eq!(game_with(Rules::candlepin(), &rolls).score(), Some(12));
+
+    let mut rolls = vec![0; 27];
+    rolls.extend([10, 10, 10]);
+    assert_eq!(game_with(Rules::candlepin(), &rolls).score(), Some(30));
+}
+
+#[test]
+fn a_perfect_duckpin_game() {
+    let game = game_with(Rules::duckpin(), &[10; 12]);
+
+    assert_eq!(game.score(), Some(300));
+}
+
+#[test]
+fn parse_and_render_with_rules() {
+    let rules = Rules::candlepin().with_frames(3);
+    let game = BowlingGame::parse_with_rules("X 5/ 3-2", rules).unwrap();
+
+    assert_eq!(game.score(), Some(38));
+    assert_eq!(
+        game.to_string(),
+        "| X   | 5/  | 3-2 |\n\
+         |  20 |  33 |  38 |"
+    );
+}
+
+#[test]
+fn undo_keeps_the_rules() {
+    let mut game = game_with(Rules::nine_pin(), &[9, 4]);
+
+    assert_eq!(game.undo(), Some(4));
+    assert_eq!(game.rules(), Rules::nine_pin());
+    assert_eq!(game.roll(9), Ok(()));
+}
+
+#[test]
+#[should_panic]
+fn a_game_needs_at_least_one_frame() {
+    Rules::ten_pin().with_frames(0);
+}
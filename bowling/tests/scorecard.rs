@@ -0,0 +1,79 @@
+use bowling::*;
+
+fn game_with(rolls: &[u16]) -> BowlingGame {
+    let mut game = BowlingGame::new();
+    for &pins in rolls {
+        game.roll(pins).unwrap();
+    }
+    game
+}
+
+#[test]
+fn frame_notation_uses_strike_spare_and_gutter_marks() {
+    let game = game_with(&[10, 7, 3, 9, 0, 0, 8]);
+    let notation: Vec<_> = game
+        .frame_scores()
+        .iter()
+        .map(FrameScore::notation)
+        .collect();
+
+    assert_eq!(notation, vec!["X", "7/", "9-", "-8"]);
+}
+
+#[test]
+fn tenth_frame_notation_resets_the_rack_after_a_strike_or_spare() {
+    let mut rolls = vec![0; 18];
+    rolls.extend([10, 10, 10]);
+    assert_eq!(game_with(&rolls).frame_scores()[9].notation(), "XXX");
+
+    let mut rolls = vec![0; 18];
+    rolls.extend([10, 8, 2]);
+    assert_eq!(game_with(&rolls).frame_scores()[9].notation(), "X8/");
+
+    let mut rolls = vec![0; 18];
+    rolls.extend([6, 4, 10]);
+    assert_eq!(game_with(&rolls).frame_scores()[9].notation(), "6/X");
+}
+
+#[test]
+fn render_a_complete_game() {
+    let game = game_with(&[10, 7, 3, 9, 0, 10, 0, 8, 8, 2, 0, 6, 10, 10, 10, 8, 1]);
+
+    assert_eq!(
+        game.to_string(),
+        "| X   | 7/  | 9-  | X   | -8  | 8/  | -6  | X   | X   | X81 |\n\
+         |  20 |  39 |  48 |  66 |  74 |  84 |  90 | 120 | 148 | 167 |"
+    );
+}
+
+#[test]
+fn render_a_game_in_progress() {
+    let game = game_with(&[10, 7, 3, 4]);
+
+    assert_eq!(
+        game.to_string(),
+        "| X   | 7/  | 4   |     |     |     |     |     |     |     |\n\
+         |  20 |  34 |     |     |     |     |     |     |     |     |"
+    );
+}
+
+#[test]
+fn columns_widen_for_more_balls_and_bigger_totals() {
+    let rules = Rules::ten_pin().with_frames(2).with_balls_per_frame(4);
+    let mut game = BowlingGame::with_rules(rules);
+    for pins in [1, 1, 1, 1, 1, 1, 1, 1] {
+        game.roll(pins).unwrap();
+    }
+    assert_eq!(game.to_string(), "| 1111 | 1111 |\n|    4 |    8 |");
+
+    let mut game = BowlingGame::with_rules(Rules::ten_pin().with_frames(40));
+    for _ in 0..42 {
+        game.roll(10).unwrap();
+    }
+    let card = game.to_string();
+    let (marks, totals) = card.split_once('\n').unwrap();
+    assert!(marks.starts_with("| X    | X    |"));
+    assert!(totals.starts_with("|   30 |   60 |"));
+    assert!(totals.ends_with("| 1200 |"));
+    assert_eq!(marks.len(), totals.len());
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "serde")]
+
+use bowling::*;
+use serde_json::json;
+
+#[test]
+fn serialize_a_game_in_progress() {
+    let game: BowlingGame = "X 7/ 9- 4".parse().unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&game).unwrap(),
+        json!({
+            "rules": { "frames": 10, "pins": 10, "balls_per_frame": 2 },
+            "frames": [[10], [7, 3], [9, 0]],
+            "incomplete_frame": [4],
+        })
+    );
+}
+
+#[test]
+fn round_trip_a_game_in_progress() {
+    let game = BowlingGame::parse_with_rules("X 7/ 333 4", Rules::candlepin()).unwrap();
+    let json = serde_json::to_string(&game).unwrap();
+    let restored: BowlingGame = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.rules(), Rules::candlepin());
+    assert_eq!(restored.rolls(), game.rolls());
+    assert_eq!(restored.frame_scores(), game.frame_scores());
+}
+
+#[test]
+fn round_trip_a_finished_game() {
+    let game: BowlingGame = "X 7/ 9- X -8 8/ -6 X X X81".parse().unwrap();
+    let restored: BowlingGame =
+        serde_json::from_value(serde_json::to_value(&game).unwrap()).unwrap();
+
+    assert_eq!(restored.score(), Some(167));
+}
+
+fn deserialize(frames: serde_json::Value, incomplete_frame: serde_json::Value) -> bool {
+    serde_json::from_value::<BowlingGame>(json!({
+        "rules": { "frames": 10, "pins": 10, "balls_per_frame": 2 },
+        "frames": frames,
+        "incomplete_frame": incomplete_frame,
+    }))
+    .is_ok()
+}
+
+#[test]
+fn rolls_are_validated_on_the_way_in() {
+    assert!(deserialize(json!([[10], [7, 3]]), json!([4])));
+    assert!(!deserialize(json!([[7, 4]]), json!(null)));
+    assert!(!deserialize(json!([[11]]), json!(null)));
+    assert!(!deserialize(json!(vec![[0, 0]; 11]), json!(null)));
+}
+
+#[test]
+fn frames_must_end_where_the_rolls_say() {
+    assert!(!deserialize(json!([[7], [3]]), json!(null)));
+    assert!(!deserialize(json!([[10, 10]]), json!(null)));
+    assert!(!deserialize(json!([]), json!([7, 3])));
+    assert!(!deserialize(json!([]), json!([])));
+}
+
+#[test]
+fn invalid_rules_are_rejected() {
+    let game = json!({
+        "rules": { "frames": 0, "pins": 10, "balls_per_frame": 2 },
+        "frames": [],
+        "incomplete_frame": null,
+    });
+
+    assert!(serde_json::from_value::<BowlingGame>(game).is_err());
+}
+
+#[test]
+fn round_trip_a_game_rolled_by_pins() {
+    let mut game = BowlingGame::new();
+    game.roll_standing(PinSet::from_pins(&[7, 10]).unwrap())
+        .unwrap();
+    game.roll(1).unwrap();
+
+    let value = serde_json::to_value(&game).unwrap();
+    assert_eq!(value["leaves"], json!([576, null]));
+
+    let restored: BowlingGame = serde_json::from_value(value).unwrap();
+    assert_eq!(restored.first_ball_leaves(), game.first_ball_leaves());
+}
+
+#[test]
+fn leaves_must_match_the_rolls() {
+    let game = json!({
+        "rules": { "frames": 10, "pins": 10, "balls_per_frame": 2 },
+        "frames": [[7, 1]],
+        "incomplete_frame": null,
+        "leaves": [576, null],
+    });
+
+    assert!(serde_json::from_value::<BowlingGame>(game).is_err());
+}
@@ -0,0 +1,87 @@
+use bowling::*;
+
+fn game(notation: &str) -> BowlingGame {
+    notation.parse().unwrap()
+}
+
+#[test]
+fn stats_for_a_single_game() {
+    let stats = BowlerStats::from_games(&[game("X 7/ 9- X -8 8/ -6 X X X81")]);
+
+    assert_eq!(
+        stats,
+        BowlerStats {
+            games: 1,
+            frames: 10,
+            strikes: 5,
+            spares: 2,
+            open_frames: 3,
+            first_ball_pins: 74,
+            clean_games: 0,
+            splits: 0,
+            splits_converted: 0,
+            split_free_games: 0,
+        }
+    );
+    assert_eq!(stats.strike_percentage(), 50.0);
+    assert_eq!(stats.spare_conversion_percentage(), 40.0);
+    assert_eq!(stats.open_frame_percentage(), 30.0);
+    assert_eq!(stats.first_ball_average(), 7.4);
+}
+
+#[test]
+fn a_clean_game_has_no_open_frames() {
+    let stats = BowlerStats::from_games(&[
+        game("X 7/ 9/ X 5/ 8/ X X X X81"),
+        game("X X X X X X X X X XXX"),
+        game("-- -- -- -- -- -- -- -- -- --"),
+    ]);
+
+    assert_eq!(stats.games, 3);
+    assert_eq!(stats.clean_games, 2);
+    assert_eq!(stats.open_frames, 10);
+}
+
+#[test]
+fn unfinished_games_are_skipped() {
+    let stats = BowlerStats::from_games(&[game("X X X")]);
+
+    assert_eq!(stats, BowlerStats::default());
+    assert_eq!(stats.strike_percentage(), 0.0);
+    assert_eq!(stats.first_ball_average(), 0.0);
+}
+
+#[test]
+fn stats_are_grouped_by_bowler() {
+    let perfect = game("X X X X X X X X X XXX");
+    let gutter = game("-- -- -- -- -- -- -- -- -- --");
+    let stats = stats_by_bowler([("Ann", &perfect), ("Bob", &gutter), ("Ann", &gutter)]);
+
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats["Ann"].games, 2);
+    assert_eq!(stats["Ann"].strike_percentage(), 50.0);
+    assert_eq!(stats["Bob"].open_frame_percentage(), 100.0);
+    assert_eq!(stats["Bob"].spare_conversion_percentage(), 0.0);
+}
+
+fn game_by_pins(leaves: &[&[u8]]) -> BowlingGame {
+    let mut game = BowlingGame::new();
+    for pins in leaves {
+        game.roll_standing(PinSet::from_pins(pins).unwrap())
+            .unwrap();
+    }
+    game
+}
+
+#[test]
+fn splits_need_pin_by_pin_games() {
+    let mut leaves: Vec<&[u8]> = vec![&[7, 10], &[], &[4, 6, 7, 10], &[4, 6]];
+    leaves.extend([&[][..]; 10]);
+    let split_game = game_by_pins(&leaves);
+    let clean_game = game_by_pins(&[&[][..]; 12]);
+    let stats = BowlerStats::from_games(&[split_game, clean_game, game("X X X X X X X X X XXX")]);
+
+    assert_eq!(stats.splits, 2);
+    assert_eq!(stats.splits_converted, 1);
+    assert_eq!(stats.split_free_games, 1);
+}
@@ -0,0 +1,119 @@
+use crate::{Clock, ClockDuration, ClockRange, DateTimeless, Schedule};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recurrence {
+    /// Every day at the given time.
+    Daily(Clock),
+    /// Every `interval` from the start of `window` for as long as it lasts, every day.
+    Every {
+        interval: ClockDuration,
+        window: ClockRange,
+    },
+}
+
+impl Recurrence {
+    fn schedule(&self) -> Schedule {
+        match self {
+            Recurrence::Daily(clock) => [*clock].into_iter().collect(),
+            Recurrence::Every { interval, window } => window.steps(*interval).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AlarmId(usize);
+
+struct Alarm {
+    id: AlarmId,
+    schedule: Schedule,
+    next: Option<DateTimeless>,
+    callback: Box<dyn FnMut(AlarmId, DateTimeless)>,
+}
+
+/// Runs recurring alarms against simulated time that only moves when told to.
+pub struct AlarmScheduler {
+    now: DateTimeless,
+    next_id: usize,
+    alarms: Vec<Alarm>,
+}
+
+/// The first time after `time` that appears in the schedule.
+fn next_after(schedule: &Schedule, time: DateTimeless) -> Option<DateTimeless> {
+    let next = schedule.next_after(time.clock())?;
+    let days = if next > time.clock() {
+        time.days()
+    } else {
+        time.days() + 1
+    };
+    Some(DateTimeless::new(days, next))
+}
+
+impl AlarmScheduler {
+    pub fn new(now: DateTimeless) -> Self {
+        Self {
+            now,
+            next_id: 0,
+            alarms: vec![],
+        }
+    }
+
+    pub fn now(&self) -> DateTimeless {
+        self.now
+    }
+
+    /// Registers an alarm; it first goes off at its next occurrence after now.
+    /// Panics if an `Every` interval isn't positive.
+    pub fn add(
+        &mut self,
+        recurrence: Recurrence,
+        callback: impl FnMut(AlarmId, DateTimeless) + 'static,
+    ) -> AlarmId {
+        let id = AlarmId(self.next_id);
+        self.next_id += 1;
+
+        let schedule = recurrence.schedule();
+        self.alarms.push(Alarm {
+            id,
+            next: next_after(&schedule, self.now),
+            schedule,
+            callback: Box::new(callback),
+        });
+        id
+    }
+
+    pub fn remove(&mut self, id: AlarmId) -> bool {
+        let before = self.alarms.len();
+        self.alarms.retain(|alarm| alarm.id != id);
+        self.alarms.len() != before
+    }
+
+    /// The alarm that goes off next, earliest registered first on a tie.
+    pub fn next_alarm(&self) -> Option<(AlarmId, DateTimeless)> {
+        self.alarms
+            .iter()
+            .filter_map(|alarm| alarm.next.map(|next| (next, alarm.id)))
+            .min()
+            .map(|(next, id)| (id, next))
+    }
+
+    pub fn advance(&mut self, by: ClockDuration) -> Vec<(AlarmId, DateTimeless)> {
+        self.advance_to(self.now + by)
+    }
+
+    /// Moves time forward to `time`, firing every alarm due on the way in order.
+    /// Returns which alarms went off and when. Time never moves backwards.
+    pub fn advance_to(&mut self, time: DateTimeless) -> Vec<(AlarmId, DateTimeless)> {
+        let mut fired = vec![];
+
+        while let Some((id, at)) = self.next_alarm().filter(|&(_, at)| at <= time) {
+            self.now = at;
+            let alarm = self.alarms.iter_mut().find(|alarm| alarm.id == id).unwrap();
+            (alarm.callback)(id, at);
+            alarm.next = next_after(&alarm.schedule, at);
+            fired.push((id, at));
+        }
+
+        self.now = self.now.max(time);
+        fired
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Clock, ClockDuration, MINUTES_PER_DAY};
+use std::fmt::Display;
+use std::ops::{Add, Sub};
+
+/// A time of day together with how many days it is away from day zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTimeless {
+    days: i32,
+    clock: Clock,
+}
+
+impl DateTimeless {
+    pub fn new(days: i32, clock: Clock) -> Self {
+        Self { days, clock }
+    }
+
+    pub fn days(&self) -> i32 {
+        self.days
+    }
+
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    pub fn add_minutes(&self, minutes: i32) -> Self {
+        let minutes = self.clock.minutes + minutes;
+        Self {
+            days: self.days + minutes.div_euclid(MINUTES_PER_DAY),
+            clock: Clock::new(0, minutes),
+        }
+    }
+
+    /// Minutes since midnight of day zero.
+    pub(crate) fn as_minutes(&self) -> i64 {
+        i64::from(self.days) * i64::from(MINUTES_PER_DAY) + i64::from(self.clock.minutes)
+    }
+}
+
+impl Clock {
+    /// Like `add_minutes`, but keeps count of the midnights crossed, negative when going back.
+    pub fn add_minutes_carrying_days(&self, minutes: i32) -> DateTimeless {
+        DateTimeless::new(0, *self).add_minutes(minutes)
+    }
+}
+
+impl From<Clock> for DateTimeless {
+    fn from(clock: Clock) -> Self {
+        Self::new(0, clock)
+    }
+}
+
+impl Add<ClockDuration> for DateTimeless {
+    type Output = DateTimeless;
+
+    fn add(self, rhs: ClockDuration) -> Self::Output {
+        self.add_minutes(rhs.as_minutes())
+    }
+}
+
+impl Sub<ClockDuration> for DateTimeless {
+    type Output = DateTimeless;
+
+    fn sub(self, rhs: ClockDuration) -> Self::Output {
+        self.add_minutes(-rhs.as_minutes())
+    }
+}
+
+/// The exact time between the two, negative if `rhs` is later.
+impl Sub for DateTimeless {
+    type Output = ClockDuration;
+
+    fn sub(self, rhs: DateTimeless) -> Self::Output {
+        ClockDuration::from_minutes((self.as_minutes() - rhs.as_minutes()) as i32)
+    }
+}
+
+/// The clock, followed by the day offset when there is one, e.g. `06:00 +1`.
+impl Display for DateTimeless {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.clock)?;
+        if self.days != 0 {
+            write!(f, " {:+}", self.days)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{Clock, MINUTES_PER_DAY};
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A span of time in whole minutes. Unlike a `Clock` it doesn't wrap at a day
+/// and can be negative.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClockDuration {
+    minutes: i32,
+}
+
+impl ClockDuration {
+    pub fn new(hours: i32, minutes: i32) -> Self {
+        Self::from_minutes(hours * 60 + minutes)
+    }
+
+    pub fn from_minutes(minutes: i32) -> Self {
+        Self { minutes }
+    }
+
+    pub fn as_minutes(&self) -> i32 {
+        self.minutes
+    }
+}
+
+impl Add for ClockDuration {
+    type Output = ClockDuration;
+
+    fn add(self, rhs: ClockDuration) -> Self::Output {
+        Self::from_minutes(self.minutes + rhs.minutes)
+    }
+}
+
+impl Sub for ClockDuration {
+    type Output = ClockDuration;
+
+    fn sub(self, rhs: ClockDuration) -> Self::Output {
+        Self::from_minutes(self.minutes - rhs.minutes)
+    }
+}
+
+impl Neg for ClockDuration {
+    type Output = ClockDuration;
+
+    fn neg(self) -> Self::Output {
+        Self::from_minutes(-self.minutes)
+    }
+}
+
+impl Add<ClockDuration> for Clock {
+    type Output = Clock;
+
+    fn add(self, rhs: ClockDuration) -> Self::Output {
+        self.add_minutes(rhs.minutes)
+    }
+}
+
+impl Sub<ClockDuration> for Clock {
+    type Output = Clock;
+
+    fn sub(self, rhs: ClockDuration) -> Self::Output {
+        self.add_minutes(-rhs.minutes)
+    }
+}
+
+impl AddAssign<ClockDuration> for Clock {
+    fn add_assign(&mut self, rhs: ClockDuration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<ClockDuration> for Clock {
+    fn sub_assign(&mut self, rhs: ClockDuration) {
+        *self = *self - rhs;
+    }
+}
+
+/// How far forward `self` is from `rhs`, e.g. 06:00 - 22:00 is 8 hours.
+impl Sub for Clock {
+    type Output = ClockDuration;
+
+    fn sub(self, rhs: Clock) -> Self::Output {
+        ClockDuration::from_minutes((self.minutes - rhs.minutes).rem_euclid(MINUTES_PER_DAY))
+    }
+}
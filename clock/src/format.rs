@@ -0,0 +1,143 @@
+use crate::Clock;
+
+/// Options for writing a clock other than the default `HH:MM`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClockFormat {
+    twelve_hour: bool,
+    leading_zero: bool,
+    separator: String,
+}
+
+impl ClockFormat {
+    pub fn new() -> Self {
+        Self {
+            twelve_hour: false,
+            leading_zero: true,
+            separator: ":".to_string(),
+        }
+    }
+
+    /// Hours from 1 to 12 followed by ` AM` or ` PM`.
+    pub fn with_twelve_hour(&self, twelve_hour: bool) -> Self {
+        let mut format = self.clone();
+        format.twelve_hour = twelve_hour;
+        format
+    }
+
+    /// Whether single digit hours are padded, `07:05` rather than `7:05`.
+    pub fn with_leading_zero(&self, leading_zero: bool) -> Self {
+        let mut format = self.clone();
+        format.leading_zero = leading_zero;
+        format
+    }
+
+    pub fn with_separator(&self, separator: &str) -> Self {
+        let mut format = self.clone();
+        format.separator = separator.to_string();
+        format
+    }
+}
+
+impl Default for ClockFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock {
+    /// Hours past midnight, 0 to 23.
+    pub fn hour(&self) -> i32 {
+        self.minutes / 60
+    }
+
+    /// Minutes past the hour, 0 to 59.
+    pub fn minute(&self) -> i32 {
+        self.minutes % 60
+    }
+
+    fn hour_12(&self) -> i32 {
+        match self.hour() % 12 {
+            0 => 12,
+            hour => hour,
+        }
+    }
+
+    fn meridiem(&self) -> &'static str {
+        if self.hour() < 12 {
+            "AM"
+        } else {
+            "PM"
+        }
+    }
+
+    pub fn format_with(&self, format: &ClockFormat) -> String {
+        let hour = if format.twelve_hour {
+            self.hour_12()
+        } else {
+            self.hour()
+        };
+
+        let mut formatted = if format.leading_zero {
+            format!("{:02}", hour)
+        } else {
+            hour.to_string()
+        };
+        formatted.push_str(&format.separator);
+        formatted.push_str(&format!("{:02}", self.minute()));
+
+        if format.twelve_hour {
+            formatted.push(' ');
+            formatted.push_str(self.meridiem());
+        }
+        formatted
+    }
+
+    /// Formats with a strftime-like pattern. Supports `%H`, `%I`, `%M`, `%p`, `%P`,
+    /// `%R` and `%%`; a `-` after the `%` drops the leading zero, as in `%-I`.
+    /// Anything else is copied as is.
+    pub fn format(&self, pattern: &str) -> String {
+        let mut formatted = String::new();
+        let mut chars = pattern.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                formatted.push(c);
+                continue;
+            }
+
+            let pad = chars.next_if_eq(&'-').is_none();
+            let number = |n: i32| {
+                if pad {
+                    format!("{:02}", n)
+                } else {
+                    n.to_string()
+                }
+            };
+
+            match chars.next() {
+                Some('H') => formatted.push_str(&number(self.hour())),
+                Some('I') => formatted.push_str(&number(self.hour_12())),
+                Some('M') => formatted.push_str(&number(self.minute())),
+                Some('p') => formatted.push_str(self.meridiem()),
+                Some('P') => formatted.push_str(&self.meridiem().to_lowercase()),
+                Some('R') => formatted.push_str(&self.to_string()),
+                Some('%') => formatted.push('%'),
+                Some(other) => {
+                    formatted.push('%');
+                    if !pad {
+                        formatted.push('-');
+                    }
+                    formatted.push(other);
+                }
+                None => {
+                    formatted.push('%');
+                    if !pad {
+                        formatted.push('-');
+                    }
+                }
+            }
+        }
+
+        formatted
+    }
+}
@@ -1,6 +1,28 @@
 use std::fmt::Display;
 
-#[derive(Debug, PartialEq, Eq)]
+mod alarm;
+mod day;
+mod duration;
+mod format;
+mod parse;
+#[cfg(feature = "serde")]
+mod persistence;
+mod precise;
+mod range;
+mod schedule;
+mod zone;
+
+pub use alarm::{AlarmId, AlarmScheduler, Recurrence};
+pub use day::DateTimeless;
+pub use duration::ClockDuration;
+pub use format::ClockFormat;
+pub use parse::ParseClockError;
+pub use precise::PreciseClock;
+pub use range::ClockRange;
+pub use schedule::Schedule;
+pub use zone::{UtcOffset, ZonedClock};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Clock {
     minutes: i32,
 }
@@ -24,8 +46,6 @@ impl Clock {
 
 impl Display for Clock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let hours = self.minutes / 60;
-        let minutes = self.minutes % 60;
-        write!(f, "{:02}:{:02}", hours, minutes)
+        write!(f, "{:02}:{:02}", self.hour(), self.minute())
     }
 }
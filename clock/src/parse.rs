@@ -0,0 +1,59 @@
+use crate::Clock;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseClockError {
+    Empty,
+    InvalidFormat,
+    HourOutOfRange(u32),
+    MinuteOutOfRange(u32),
+}
+
+fn parse_digits(digits: &str, min_len: usize) -> Result<u32, ParseClockError> {
+    if !(min_len..=2).contains(&digits.len()) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseClockError::InvalidFormat);
+    }
+    digits.parse().map_err(|_| ParseClockError::InvalidFormat)
+}
+
+/// Accepts `HH:MM`, `H:MM`, 12-hour times like `7:05 pm` and ISO times like `T14:30`.
+impl FromStr for Clock {
+    type Err = ParseClockError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseClockError::Empty);
+        }
+
+        let lowercase = s.to_ascii_lowercase();
+        let (time, pm) = match lowercase.strip_suffix("am") {
+            Some(time) => (time.trim_end(), Some(false)),
+            None => match lowercase.strip_suffix("pm") {
+                Some(time) => (time.trim_end(), Some(true)),
+                None => (lowercase.as_str(), None),
+            },
+        };
+
+        let (time, iso) = match time.strip_prefix('t') {
+            Some(time) if pm.is_none() => (time, true),
+            Some(_) => return Err(ParseClockError::InvalidFormat),
+            None => (time, false),
+        };
+
+        let (hours, minutes) = time.split_once(':').ok_or(ParseClockError::InvalidFormat)?;
+        let hours = parse_digits(hours, if iso { 2 } else { 1 })?;
+        let minutes = parse_digits(minutes, 2)?;
+
+        let hours = match pm {
+            None if hours < 24 => hours,
+            Some(pm) if (1..=12).contains(&hours) => hours % 12 + if pm { 12 } else { 0 },
+            _ => return Err(ParseClockError::HourOutOfRange(hours)),
+        };
+        if minutes >= 60 {
+            return Err(ParseClockError::MinuteOutOfRange(minutes));
+        }
+
+        Ok(Clock::new(hours as i32, minutes as i32))
+    }
+}
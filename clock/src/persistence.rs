@@ -0,0 +1,58 @@
+use crate::{Clock, MINUTES_PER_DAY};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// `"HH:MM"` for human-readable formats, the minute of the day as a `u16` otherwise.
+impl Serialize for Clock {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u16(self.minutes as u16)
+        }
+    }
+}
+
+/// Unlike `Clock::new`, out of range values are rejected rather than wrapped.
+impl<'de> Deserialize<'de> for Clock {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ClockVisitor)
+        } else {
+            deserializer.deserialize_u16(ClockVisitor)
+        }
+    }
+}
+
+struct ClockVisitor;
+
+impl<'de> Visitor<'de> for ClockVisitor {
+    type Value = Clock;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a time as \"HH:MM\" or a minute of the day below 1440")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let strict = v.len() == 5
+            && v.bytes().enumerate().all(|(i, b)| {
+                if i == 2 {
+                    b == b':'
+                } else {
+                    b.is_ascii_digit()
+                }
+            });
+        if !strict {
+            return Err(E::invalid_value(de::Unexpected::Str(v), &self));
+        }
+        v.parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        if v >= MINUTES_PER_DAY as u64 {
+            return Err(E::invalid_value(de::Unexpected::Unsigned(v), &self));
+        }
+        Ok(Clock::new(0, v as i32))
+    }
+}
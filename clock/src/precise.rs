@@ -0,0 +1,51 @@
+use crate::Clock;
+use std::fmt::Display;
+
+/// A clock that keeps seconds as well as minutes.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PreciseClock {
+    seconds: i32,
+}
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+impl PreciseClock {
+    pub fn new(hours: i32, minutes: i32, seconds: i32) -> Self {
+        Self::from_seconds((i64::from(hours) * 60 + i64::from(minutes)) * 60 + i64::from(seconds))
+    }
+
+    /// Worked out in `i64` so large hour or minute counts wrap instead of overflowing.
+    fn from_seconds(seconds: i64) -> Self {
+        Self {
+            seconds: seconds.rem_euclid(SECONDS_PER_DAY) as i32,
+        }
+    }
+
+    pub fn add_seconds(&self, seconds: i32) -> Self {
+        Self::from_seconds(i64::from(self.seconds) + i64::from(seconds))
+    }
+
+    pub fn add_minutes(&self, minutes: i32) -> Self {
+        Self::from_seconds(i64::from(self.seconds) + i64::from(minutes) * 60)
+    }
+
+    /// Drops the seconds.
+    pub fn to_clock(&self) -> Clock {
+        Clock::new(0, self.seconds / 60)
+    }
+}
+
+impl From<Clock> for PreciseClock {
+    fn from(clock: Clock) -> Self {
+        Self::new(0, clock.minutes, 0)
+    }
+}
+
+impl Display for PreciseClock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hours = self.seconds / 3600;
+        let minutes = self.seconds / 60 % 60;
+        let seconds = self.seconds % 60;
+        write!(f, "{:02}:{:02}:{:02}", hours, minutes, seconds)
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{Clock, ClockDuration, MINUTES_PER_DAY};
+
+/// Times of day from `start` up to but not including `end`, possibly across midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockRange {
+    start: Clock,
+    duration: ClockDuration,
+}
+
+impl ClockRange {
+    /// An empty range when `start` and `end` are the same.
+    pub fn new(start: Clock, end: Clock) -> Self {
+        Self {
+            start,
+            duration: end - start,
+        }
+    }
+
+    /// A range of the given length, capped at a whole day.
+    pub fn from_duration(start: Clock, duration: ClockDuration) -> Self {
+        let minutes = duration.as_minutes().clamp(0, MINUTES_PER_DAY);
+        Self {
+            start,
+            duration: ClockDuration::from_minutes(minutes),
+        }
+    }
+
+    pub fn start(&self) -> Clock {
+        self.start
+    }
+
+    pub fn end(&self) -> Clock {
+        self.start + self.duration
+    }
+
+    pub fn duration(&self) -> ClockDuration {
+        self.duration
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.duration.as_minutes() == 0
+    }
+
+    pub fn wraps_midnight(&self) -> bool {
+        self.start.minutes + self.duration.as_minutes() > MINUTES_PER_DAY
+    }
+
+    pub fn contains(&self, clock: Clock) -> bool {
+        clock - self.start < self.duration
+    }
+
+    pub fn overlaps(&self, other: &ClockRange) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The parts of the day both ranges cover. Two ranges that wrap midnight
+    /// differently can share two separate stretches, e.g. 22:00-06:00 and
+    /// 04:00-23:00 meet at 04:00-06:00 and 22:00-23:00.
+    pub fn intersection(&self, other: &ClockRange) -> Vec<ClockRange> {
+        let mut shared: Vec<(i32, i32)> = vec![];
+        for (a_start, a_end) in self.segments() {
+            for (b_start, b_end) in other.segments() {
+                let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+                if start < end {
+                    shared.push((start, end));
+                }
+            }
+        }
+        shared.sort_unstable();
+
+        // merge pieces that touch, e.g. both halves of two whole days starting at different times
+        let mut merged: Vec<(i32, i32)> = vec![];
+        for (start, end) in shared {
+            match merged.last_mut() {
+                Some(last) if last.1 >= start => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        let mut shared = merged;
+        if shared == [(0, MINUTES_PER_DAY)] {
+            return vec![ClockRange::from_duration(self.start, self.duration)];
+        }
+
+        // stitch back together a stretch that was cut at midnight
+        if shared.len() > 1 && shared[0].0 == 0 && shared[shared.len() - 1].1 == MINUTES_PER_DAY {
+            let (_, end) = shared.remove(0);
+            shared.last_mut().unwrap().1 += end;
+        }
+
+        shared
+            .into_iter()
+            .map(|(start, end)| {
+                ClockRange::from_duration(
+                    Clock::new(0, start),
+                    ClockDuration::from_minutes(end - start),
+                )
+            })
+            .collect()
+    }
+
+    /// Minutes of the day covered, split in two where the range crosses midnight.
+    fn segments(&self) -> Vec<(i32, i32)> {
+        let start = self.start.minutes;
+        let end = start + self.duration.as_minutes();
+        if self.is_empty() {
+            vec![]
+        } else if end <= MINUTES_PER_DAY {
+            vec![(start, end)]
+        } else {
+            vec![(start, MINUTES_PER_DAY), (0, end - MINUTES_PER_DAY)]
+        }
+    }
+
+    /// Every `step` from the start while still inside the range. Panics unless `step` is positive.
+    pub fn steps(&self, step: ClockDuration) -> impl Iterator<Item = Clock> {
+        assert!(step.as_minutes() > 0, "step must be positive");
+        let start = self.start;
+        (0..self.duration.as_minutes())
+            .step_by(step.as_minutes() as usize)
+            .map(move |offset| start.add_minutes(offset))
+    }
+}
@@ -0,0 +1,67 @@
+use crate::Clock;
+use std::collections::BTreeSet;
+use std::ops::Bound::{Excluded, Unbounded};
+
+/// Times of day in order, repeating every day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schedule {
+    events: BTreeSet<Clock>,
+}
+
+impl Schedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `false` if the event was already scheduled.
+    pub fn insert(&mut self, event: Clock) -> bool {
+        self.events.insert(event)
+    }
+
+    pub fn remove(&mut self, event: &Clock) -> bool {
+        self.events.remove(event)
+    }
+
+    pub fn contains(&self, event: &Clock) -> bool {
+        self.events.contains(event)
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Events from midnight onwards.
+    pub fn iter(&self) -> impl Iterator<Item = &Clock> {
+        self.events.iter()
+    }
+
+    /// The first event after `clock`, wrapping round to tomorrow's first event.
+    pub fn next_after(&self, clock: Clock) -> Option<Clock> {
+        self.events
+            .range((Excluded(clock), Unbounded))
+            .next()
+            .or_else(|| self.events.first())
+            .copied()
+    }
+
+    /// The last event before `clock`, wrapping round to yesterday's last event.
+    pub fn previous_before(&self, clock: Clock) -> Option<Clock> {
+        self.events
+            .range(..clock)
+            .next_back()
+            .or_else(|| self.events.last())
+            .copied()
+    }
+}
+
+impl FromIterator<Clock> for Schedule {
+    fn from_iter<T: IntoIterator<Item = Clock>>(iter: T) -> Self {
+        Self {
+            events: iter.into_iter().collect(),
+        }
+    }
+}
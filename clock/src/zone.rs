@@ -0,0 +1,97 @@
+use crate::{Clock, DateTimeless};
+use std::fmt::Display;
+
+/// A fixed offset from UTC, between -12:00 and +14:00.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcOffset {
+    minutes: i32,
+}
+
+impl UtcOffset {
+    pub const UTC: UtcOffset = UtcOffset { minutes: 0 };
+
+    /// `None` outside -12:00 to +14:00.
+    pub fn from_minutes(minutes: i32) -> Option<Self> {
+        (-12 * 60..=14 * 60)
+            .contains(&minutes)
+            .then_some(Self { minutes })
+    }
+
+    /// Ahead of UTC, e.g. `east(5, 45)` for Nepal's +05:45.
+    pub fn east(hours: i32, minutes: i32) -> Option<Self> {
+        if !(0..60).contains(&minutes) || hours < 0 {
+            return None;
+        }
+        Self::from_minutes(hours * 60 + minutes)
+    }
+
+    /// Behind UTC, e.g. `west(3, 30)` for Newfoundland's -03:30.
+    pub fn west(hours: i32, minutes: i32) -> Option<Self> {
+        if !(0..60).contains(&minutes) || hours < 0 {
+            return None;
+        }
+        Self::from_minutes(-(hours * 60 + minutes))
+    }
+
+    pub fn as_minutes(&self) -> i32 {
+        self.minutes
+    }
+}
+
+/// `+05:45`, `-03:30`, `+00:00`.
+impl Display for UtcOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.minutes < 0 { '-' } else { '+' };
+        let minutes = self.minutes.abs();
+        write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
+/// A local time of day in a zone with a fixed UTC offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZonedClock {
+    clock: Clock,
+    offset: UtcOffset,
+}
+
+impl ZonedClock {
+    pub fn new(clock: Clock, offset: UtcOffset) -> Self {
+        Self { clock, offset }
+    }
+
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    pub fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    pub fn to_utc(&self) -> Clock {
+        self.clock.add_minutes(-self.offset.minutes)
+    }
+
+    /// The same moment on a clock in another zone.
+    pub fn to_offset(&self, offset: UtcOffset) -> ZonedClock {
+        Self::new(self.to_offset_carrying_days(offset).clock(), offset)
+    }
+
+    /// Like `to_offset`, but also says whether the other zone is already on the
+    /// next day (+1) or still on the previous one (-1).
+    pub fn to_offset_carrying_days(&self, offset: UtcOffset) -> DateTimeless {
+        self.clock
+            .add_minutes_carrying_days(offset.minutes - self.offset.minutes)
+    }
+
+    /// Whether both clocks show the same moment, whatever their zones.
+    pub fn same_instant(&self, other: &ZonedClock) -> bool {
+        self.to_utc() == other.to_utc()
+    }
+}
+
+/// The local time with its offset, e.g. `14:30+05:45`.
+impl Display for ZonedClock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.clock, self.offset)
+    }
+}
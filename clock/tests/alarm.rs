@@ -0,0 +1,116 @@
+use clock::{AlarmScheduler, Clock, ClockDuration, ClockRange, DateTimeless, Recurrence};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn at(days: i32, hours: i32, minutes: i32) -> DateTimeless {
+    DateTimeless::new(days, Clock::new(hours, minutes))
+}
+
+#[test]
+fn test_daily_alarm_fires_once_a_day() {
+    let mut alarms = AlarmScheduler::new(at(0, 6, 0));
+    let wake_up = alarms.add(Recurrence::Daily(Clock::new(7, 0)), |_, _| {});
+
+    assert_eq!(alarms.next_alarm(), Some((wake_up, at(0, 7, 0))));
+    assert_eq!(alarms.advance(ClockDuration::new(0, 59)), vec![]);
+    assert_eq!(
+        alarms.advance(ClockDuration::new(48, 0)),
+        vec![(wake_up, at(0, 7, 0)), (wake_up, at(1, 7, 0))]
+    );
+    assert_eq!(alarms.now(), at(2, 6, 59));
+}
+
+#[test]
+fn test_alarm_at_the_current_time_waits_for_tomorrow() {
+    let mut alarms = AlarmScheduler::new(at(0, 7, 0));
+    let wake_up = alarms.add(Recurrence::Daily(Clock::new(7, 0)), |_, _| {});
+
+    assert_eq!(alarms.next_alarm(), Some((wake_up, at(1, 7, 0))));
+}
+
+#[test]
+fn test_interval_alarm_within_a_window() {
+    let mut alarms = AlarmScheduler::new(at(0, 0, 0));
+    let window = ClockRange::new(Clock::new(22, 0), Clock::new(23, 30));
+    let rounds = alarms.add(
+        Recurrence::Every {
+            interval: ClockDuration::new(0, 40),
+            window,
+        },
+        |_, _| {},
+    );
+
+    let fired: Vec<_> = alarms
+        .advance_to(at(1, 0, 0))
+        .into_iter()
+        .map(|(id, time)| (id, time.to_string()))
+        .collect();
+    assert_eq!(
+        fired,
+        vec![
+            (rounds, "22:00".to_string()),
+            (rounds, "22:40".to_string()),
+            (rounds, "23:20".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_interval_window_across_midnight() {
+    let mut alarms = AlarmScheduler::new(at(0, 12, 0));
+    let window = ClockRange::new(Clock::new(23, 0), Clock::new(1, 0));
+    alarms.add(
+        Recurrence::Every {
+            interval: ClockDuration::new(1, 0),
+            window,
+        },
+        |_, _| {},
+    );
+
+    let fired: Vec<_> = alarms
+        .advance(ClockDuration::new(24, 0))
+        .into_iter()
+        .map(|(_, time)| time)
+        .collect();
+    assert_eq!(fired, vec![at(0, 23, 0), at(1, 0, 0)]);
+}
+
+#[test]
+fn test_callbacks_fire_in_time_order() {
+    let log = Rc::new(RefCell::new(vec![]));
+    let mut alarms = AlarmScheduler::new(at(0, 0, 0));
+
+    let record = |name: &'static str| {
+        let log = Rc::clone(&log);
+        move |_, time: DateTimeless| log.borrow_mut().push(format!("{} {}", name, time))
+    };
+    alarms.add(Recurrence::Daily(Clock::new(9, 0)), record("standup"));
+    alarms.add(Recurrence::Daily(Clock::new(8, 0)), record("coffee"));
+    alarms.add(Recurrence::Daily(Clock::new(9, 0)), record("backup"));
+
+    alarms.advance(ClockDuration::new(24, 0));
+    assert_eq!(
+        *log.borrow(),
+        vec!["coffee 08:00", "standup 09:00", "backup 09:00"]
+    );
+}
+
+#[test]
+fn test_removed_alarms_stop_firing() {
+    let mut alarms = AlarmScheduler::new(at(0, 0, 0));
+    let coffee = alarms.add(Recurrence::Daily(Clock::new(8, 0)), |_, _| {});
+
+    assert!(alarms.remove(coffee));
+    assert!(!alarms.remove(coffee));
+    assert_eq!(alarms.next_alarm(), None);
+    assert_eq!(alarms.advance(ClockDuration::new(24, 0)), vec![]);
+}
+
+#[test]
+fn test_time_never_goes_backwards() {
+    let mut alarms = AlarmScheduler::new(at(1, 12, 0));
+    alarms.add(Recurrence::Daily(Clock::new(8, 0)), |_, _| {});
+
+    assert_eq!(alarms.advance_to(at(0, 0, 0)), vec![]);
+    assert_eq!(alarms.now(), at(1, 12, 0));
+}
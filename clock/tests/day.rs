@@ -0,0 +1,62 @@
+use clock::{Clock, ClockDuration, DateTimeless};
+
+#[test]
+fn test_adding_within_the_day_carries_nothing() {
+    let later = Clock::new(10, 0).add_minutes_carrying_days(90);
+    assert_eq!(later, DateTimeless::new(0, Clock::new(11, 30)));
+    assert_eq!(later.to_string(), "11:30");
+}
+
+#[test]
+fn test_adding_past_midnight_carries_days() {
+    let later = Clock::new(22, 0).add_minutes_carrying_days(8 * 60);
+    assert_eq!(later.days(), 1);
+    assert_eq!(later.clock(), Clock::new(6, 0));
+    assert_eq!(later.to_string(), "06:00 +1");
+
+    let later = Clock::new(0, 0).add_minutes_carrying_days(3 * 1440 + 1);
+    assert_eq!(later, DateTimeless::new(3, Clock::new(0, 1)));
+}
+
+#[test]
+fn test_going_back_carries_negative_days() {
+    let earlier = Clock::new(1, 0).add_minutes_carrying_days(-120);
+    assert_eq!(earlier, DateTimeless::new(-1, Clock::new(23, 0)));
+    assert_eq!(earlier.to_string(), "23:00 -1");
+
+    let earlier = Clock::new(0, 0).add_minutes_carrying_days(-1440);
+    assert_eq!(earlier, DateTimeless::new(-1, Clock::new(0, 0)));
+}
+
+#[test]
+fn test_clock_matches_add_minutes() {
+    for minutes in [-4820, -1441, -1, 0, 59, 1440, 3001] {
+        let clock = Clock::new(13, 17);
+        assert_eq!(
+            clock.add_minutes_carrying_days(minutes).clock(),
+            clock.add_minutes(minutes)
+        );
+    }
+}
+
+#[test]
+fn test_day_offsets_keep_accumulating() {
+    let timesheet = DateTimeless::from(Clock::new(20, 0))
+        + ClockDuration::new(10, 0)
+        + ClockDuration::new(20, 0);
+    assert_eq!(timesheet, DateTimeless::new(2, Clock::new(2, 0)));
+    assert_eq!(
+        timesheet - ClockDuration::new(50, 0),
+        DateTimeless::new(0, Clock::new(0, 0))
+    );
+}
+
+#[test]
+fn test_difference_and_ordering() {
+    let start = DateTimeless::new(0, Clock::new(22, 0));
+    let end = DateTimeless::new(1, Clock::new(6, 30));
+    assert_eq!(end - start, ClockDuration::new(8, 30));
+    assert_eq!(start - end, ClockDuration::new(-8, -30));
+    assert!(start < end);
+    assert!(DateTimeless::new(-1, Clock::new(23, 0)) < DateTimeless::new(0, Clock::new(1, 0)));
+}
@@ -0,0 +1,70 @@
+use clock::{Clock, ClockDuration};
+
+#[test]
+fn test_duration_from_hours_and_minutes() {
+    assert_eq!(ClockDuration::new(1, 30).as_minutes(), 90);
+    assert_eq!(ClockDuration::new(-1, 30).as_minutes(), -30);
+    assert_eq!(ClockDuration::new(25, 0), ClockDuration::from_minutes(1500));
+}
+
+#[test]
+fn test_durations_add_and_subtract() {
+    let shift = ClockDuration::new(8, 0);
+    let break_time = ClockDuration::new(0, 45);
+    assert_eq!(shift - break_time, ClockDuration::new(7, 15));
+    assert_eq!(shift + break_time, ClockDuration::new(8, 45));
+    assert_eq!(-break_time, ClockDuration::from_minutes(-45));
+    assert!(break_time < shift);
+}
+
+#[test]
+fn test_add_duration_to_clock() {
+    assert_eq!(
+        Clock::new(22, 0) + ClockDuration::new(8, 0),
+        Clock::new(6, 0)
+    );
+    assert_eq!(
+        Clock::new(1, 0) - ClockDuration::new(2, 30),
+        Clock::new(22, 30)
+    );
+    assert_eq!(
+        Clock::new(1, 0) + -ClockDuration::new(2, 30),
+        Clock::new(22, 30)
+    );
+}
+
+#[test]
+fn test_assign_duration_to_clock() {
+    let mut clock = Clock::new(23, 0);
+    clock += ClockDuration::new(1, 30);
+    assert_eq!(clock, Clock::new(0, 30));
+    clock -= ClockDuration::new(0, 45);
+    assert_eq!(clock, Clock::new(23, 45));
+}
+
+#[test]
+fn test_clock_difference_is_the_forward_duration() {
+    assert_eq!(
+        Clock::new(14, 30) - Clock::new(9, 0),
+        ClockDuration::new(5, 30)
+    );
+    assert_eq!(
+        Clock::new(6, 0) - Clock::new(22, 0),
+        ClockDuration::new(8, 0)
+    );
+    assert_eq!(
+        Clock::new(9, 0) - Clock::new(9, 0),
+        ClockDuration::default()
+    );
+    assert_eq!(
+        Clock::new(8, 59) - Clock::new(9, 0),
+        ClockDuration::new(23, 59)
+    );
+}
+
+#[test]
+fn test_clock_difference_undoes_addition() {
+    let start = Clock::new(21, 15);
+    let gap = ClockDuration::new(5, 50);
+    assert_eq!((start + gap) - start, gap);
+}
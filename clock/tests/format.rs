@@ -0,0 +1,59 @@
+use clock::{Clock, ClockFormat};
+
+#[test]
+fn test_hour_and_minute() {
+    let clock = Clock::new(-1, 15);
+    assert_eq!(clock.hour(), 23);
+    assert_eq!(clock.minute(), 15);
+}
+
+#[test]
+fn test_default_format_matches_display() {
+    let clock = Clock::new(7, 5);
+    assert_eq!(clock.format_with(&ClockFormat::new()), clock.to_string());
+}
+
+#[test]
+fn test_twelve_hour_format() {
+    let format = ClockFormat::new().with_twelve_hour(true);
+    assert_eq!(Clock::new(19, 5).format_with(&format), "07:05 PM");
+    assert_eq!(Clock::new(0, 30).format_with(&format), "12:30 AM");
+    assert_eq!(Clock::new(12, 0).format_with(&format), "12:00 PM");
+}
+
+#[test]
+fn test_without_leading_zero() {
+    let format = ClockFormat::new()
+        .with_twelve_hour(true)
+        .with_leading_zero(false);
+    assert_eq!(Clock::new(19, 5).format_with(&format), "7:05 PM");
+
+    let format = ClockFormat::new().with_leading_zero(false);
+    assert_eq!(Clock::new(7, 5).format_with(&format), "7:05");
+}
+
+#[test]
+fn test_custom_separator() {
+    let format = ClockFormat::new().with_separator(".");
+    assert_eq!(Clock::new(14, 30).format_with(&format), "14.30");
+
+    let format = ClockFormat::new().with_separator("h");
+    assert_eq!(Clock::new(9, 5).format_with(&format), "09h05");
+}
+
+#[test]
+fn test_pattern_format() {
+    let clock = Clock::new(19, 5);
+    assert_eq!(clock.format("%H:%M"), "19:05");
+    assert_eq!(clock.format("%I:%M %p"), "07:05 PM");
+    assert_eq!(clock.format("%-I.%M%P"), "7.05pm");
+    assert_eq!(clock.format("%R"), "19:05");
+    assert_eq!(Clock::new(3, 7).format("%-H:%-M"), "3:7");
+}
+
+#[test]
+fn test_pattern_format_keeps_unknown_specifiers() {
+    let clock = Clock::new(8, 0);
+    assert_eq!(clock.format("100%% at %H"), "100% at 08");
+    assert_eq!(clock.format("%Y %-q %"), "%Y %-q %");
+}
@@ -0,0 +1,57 @@
+use clock::{Clock, ParseClockError};
+
+fn parse(s: &str) -> Result<Clock, ParseClockError> {
+    s.parse()
+}
+
+#[test]
+fn test_parse_24_hour_times() {
+    assert_eq!(parse("14:30"), Ok(Clock::new(14, 30)));
+    assert_eq!(parse("07:05"), Ok(Clock::new(7, 5)));
+    assert_eq!(parse("7:05"), Ok(Clock::new(7, 5)));
+    assert_eq!(parse("00:00"), Ok(Clock::new(0, 0)));
+    assert_eq!(parse("  23:59 "), Ok(Clock::new(23, 59)));
+}
+
+#[test]
+fn test_parse_12_hour_times() {
+    assert_eq!(parse("7:05 pm"), Ok(Clock::new(19, 5)));
+    assert_eq!(parse("7:05pm"), Ok(Clock::new(19, 5)));
+    assert_eq!(parse("7:05 AM"), Ok(Clock::new(7, 5)));
+    assert_eq!(parse("12:00 am"), Ok(Clock::new(0, 0)));
+    assert_eq!(parse("12:30 pm"), Ok(Clock::new(12, 30)));
+}
+
+#[test]
+fn test_parse_iso_times() {
+    assert_eq!(parse("T14:30"), Ok(Clock::new(14, 30)));
+    assert_eq!(parse("T09:00"), Ok(Clock::new(9, 0)));
+    assert_eq!(parse("T9:00"), Err(ParseClockError::InvalidFormat));
+    assert_eq!(parse("T9:00 pm"), Err(ParseClockError::InvalidFormat));
+}
+
+#[test]
+fn test_parse_round_trips_display() {
+    let clock = Clock::new(-25, -160);
+    assert_eq!(parse(&clock.to_string()), Ok(clock));
+}
+
+#[test]
+fn test_rejects_malformed_input() {
+    assert_eq!(parse(""), Err(ParseClockError::Empty));
+    assert_eq!(parse("   "), Err(ParseClockError::Empty));
+    assert_eq!(parse("1430"), Err(ParseClockError::InvalidFormat));
+    assert_eq!(parse("14:3"), Err(ParseClockError::InvalidFormat));
+    assert_eq!(parse("14:300"), Err(ParseClockError::InvalidFormat));
+    assert_eq!(parse("+1:30"), Err(ParseClockError::InvalidFormat));
+    assert_eq!(parse("ab:cd"), Err(ParseClockError::InvalidFormat));
+    assert_eq!(parse("7:05 xm"), Err(ParseClockError::InvalidFormat));
+}
+
+#[test]
+fn test_rejects_out_of_range_values() {
+    assert_eq!(parse("24:00"), Err(ParseClockError::HourOutOfRange(24)));
+    assert_eq!(parse("13:00 pm"), Err(ParseClockError::HourOutOfRange(13)));
+    assert_eq!(parse("0:30 am"), Err(ParseClockError::HourOutOfRange(0)));
+    assert_eq!(parse("10:60"), Err(ParseClockError::MinuteOutOfRange(60)));
+}
@@ -0,0 +1,75 @@
+use clock::{Clock, PreciseClock};
+
+#[test]
+fn test_formats_seconds() {
+    assert_eq!(PreciseClock::new(8, 5, 9).to_string(), "08:05:09");
+}
+
+#[test]
+fn test_seconds_roll_over() {
+    assert_eq!(PreciseClock::new(23, 59, 60).to_string(), "00:00:00");
+    assert_eq!(PreciseClock::new(1, 0, 3725).to_string(), "02:02:05");
+}
+
+#[test]
+fn test_negative_seconds_wrap_backwards() {
+    assert_eq!(PreciseClock::new(0, 0, -1).to_string(), "23:59:59");
+    assert_eq!(PreciseClock::new(-25, -160, -30).to_string(), "20:19:30");
+}
+
+#[test]
+fn test_large_hours_and_minutes_wrap_like_clock() {
+    assert_eq!(PreciseClock::new(0, 100_000_000, 0).to_string(), "10:40:00");
+    assert_eq!(
+        PreciseClock::new(0, 100_000_000, 0).to_clock(),
+        Clock::new(0, 100_000_000)
+    );
+    assert_eq!(PreciseClock::new(1_000_000, 0, 0).to_string(), "16:00:00");
+    assert_eq!(
+        PreciseClock::new(0, -100_000_000, 0).to_string(),
+        "13:20:00"
+    );
+    assert_eq!(
+        PreciseClock::new(0, 0, 0)
+            .add_minutes(40_000_000)
+            .to_string(),
+        "18:40:00"
+    );
+    assert_eq!(
+        PreciseClock::new(0, 0, 1).add_seconds(i32::MAX).to_string(),
+        "03:14:08"
+    );
+}
+
+#[test]
+fn test_add_seconds() {
+    let clock = PreciseClock::new(10, 0, 0).add_seconds(3);
+    assert_eq!(clock.to_string(), "10:00:03");
+
+    let clock = PreciseClock::new(23, 59, 59).add_seconds(2);
+    assert_eq!(clock.to_string(), "00:00:01");
+
+    let clock = PreciseClock::new(0, 0, 30).add_seconds(-90);
+    assert_eq!(clock.to_string(), "23:59:00");
+}
+
+#[test]
+fn test_add_seconds_across_several_days() {
+    let clock = PreciseClock::new(12, 0, 0).add_seconds(3 * 86_400 + 5);
+    assert_eq!(clock, PreciseClock::new(12, 0, 5));
+}
+
+#[test]
+fn test_add_minutes_keeps_the_seconds() {
+    let clock = PreciseClock::new(23, 30, 15).add_minutes(45);
+    assert_eq!(clock.to_string(), "00:15:15");
+}
+
+#[test]
+fn test_converts_to_and_from_clock() {
+    assert_eq!(
+        PreciseClock::from(Clock::new(7, 45)).to_string(),
+        "07:45:00"
+    );
+    assert_eq!(PreciseClock::new(7, 45, 59).to_clock(), Clock::new(7, 45));
+}
@@ -0,0 +1,103 @@
+use clock::{Clock, ClockDuration, ClockRange};
+
+fn range(start: (i32, i32), end: (i32, i32)) -> ClockRange {
+    ClockRange::new(Clock::new(start.0, start.1), Clock::new(end.0, end.1))
+}
+
+#[test]
+fn test_day_range() {
+    let shift = range((9, 0), (17, 0));
+    assert_eq!(shift.duration(), ClockDuration::new(8, 0));
+    assert!(!shift.wraps_midnight());
+    assert!(shift.contains(Clock::new(9, 0)));
+    assert!(shift.contains(Clock::new(16, 59)));
+    assert!(!shift.contains(Clock::new(17, 0)));
+    assert!(!shift.contains(Clock::new(8, 59)));
+}
+
+#[test]
+fn test_night_range_wraps_midnight() {
+    let night = range((22, 0), (6, 0));
+    assert_eq!(night.start(), Clock::new(22, 0));
+    assert_eq!(night.end(), Clock::new(6, 0));
+    assert_eq!(night.duration(), ClockDuration::new(8, 0));
+    assert!(night.wraps_midnight());
+    assert!(night.contains(Clock::new(23, 30)));
+    assert!(night.contains(Clock::new(0, 0)));
+    assert!(night.contains(Clock::new(5, 59)));
+    assert!(!night.contains(Clock::new(6, 0)));
+    assert!(!night.contains(Clock::new(12, 0)));
+}
+
+#[test]
+fn test_empty_and_whole_day_ranges() {
+    let empty = range((8, 0), (8, 0));
+    assert!(empty.is_empty());
+    assert!(!empty.contains(Clock::new(8, 0)));
+
+    let day = ClockRange::from_duration(Clock::new(6, 0), ClockDuration::new(30, 0));
+    assert_eq!(day.duration(), ClockDuration::new(24, 0));
+    assert!(day.contains(Clock::new(5, 59)));
+    assert_eq!(day.end(), Clock::new(6, 0));
+}
+
+#[test]
+fn test_overlaps() {
+    let night = range((22, 0), (6, 0));
+    assert!(night.overlaps(&range((5, 0), (7, 0))));
+    assert!(night.overlaps(&range((23, 0), (1, 0))));
+    assert!(!night.overlaps(&range((6, 0), (22, 0))));
+    assert!(!night.overlaps(&range((12, 0), (12, 0))));
+}
+
+#[test]
+fn test_intersection() {
+    let night = range((22, 0), (6, 0));
+    assert_eq!(
+        night.intersection(&range((20, 0), (2, 0))),
+        vec![range((22, 0), (2, 0))]
+    );
+    assert_eq!(
+        night.intersection(&range((1, 0), (3, 0))),
+        vec![range((1, 0), (3, 0))]
+    );
+    assert_eq!(night.intersection(&range((8, 0), (20, 0))), vec![]);
+}
+
+#[test]
+fn test_intersection_in_two_parts() {
+    let night = range((22, 0), (6, 0));
+    assert_eq!(
+        night.intersection(&range((4, 0), (23, 0))),
+        vec![range((4, 0), (6, 0)), range((22, 0), (23, 0))]
+    );
+}
+
+#[test]
+fn test_intersection_with_the_whole_day() {
+    let day = ClockRange::from_duration(Clock::new(0, 0), ClockDuration::new(24, 0));
+    let night = range((22, 0), (6, 0));
+    assert_eq!(day.intersection(&night), vec![night]);
+    assert_eq!(day.intersection(&day), vec![day]);
+
+    let from_ten = ClockRange::from_duration(Clock::new(10, 0), ClockDuration::new(24, 0));
+    let from_five = ClockRange::from_duration(Clock::new(5, 0), ClockDuration::new(24, 0));
+    assert_eq!(from_ten.intersection(&from_five), vec![from_ten]);
+    assert_eq!(from_five.intersection(&from_ten), vec![from_five]);
+    assert_eq!(from_ten.intersection(&night), vec![night]);
+}
+
+#[test]
+fn test_steps_across_midnight() {
+    let steps: Vec<String> = range((23, 0), (1, 0))
+        .steps(ClockDuration::new(0, 45))
+        .map(|clock| clock.to_string())
+        .collect();
+    assert_eq!(steps, vec!["23:00", "23:45", "00:30"]);
+}
+
+#[test]
+#[should_panic]
+fn test_steps_must_move_forward() {
+    let _ = range((23, 0), (1, 0)).steps(ClockDuration::default());
+}
@@ -0,0 +1,113 @@
+use clock::{Clock, Schedule};
+use std::collections::{BTreeMap, HashSet};
+
+#[test]
+fn test_clocks_are_ordered_by_time_of_day() {
+    assert!(Clock::new(9, 0) < Clock::new(17, 0));
+    assert!(Clock::new(-1, 0) > Clock::new(22, 59));
+    assert_eq!(Clock::new(24, 5).max(Clock::new(0, 4)), Clock::new(0, 5));
+
+    let mut clocks = vec![Clock::new(23, 0), Clock::new(0, 30), Clock::new(12, 0)];
+    clocks.sort();
+    assert_eq!(
+        clocks,
+        vec![Clock::new(0, 30), Clock::new(12, 0), Clock::new(23, 0)]
+    );
+}
+
+#[test]
+fn test_clocks_as_map_keys() {
+    let mut shifts = BTreeMap::new();
+    shifts.insert(Clock::new(22, 0), "night");
+    shifts.insert(Clock::new(6, 0), "day");
+    assert_eq!(shifts.values().collect::<Vec<_>>(), vec![&"day", &"night"]);
+
+    let set: HashSet<Clock> = [Clock::new(1, 0), Clock::new(25, 0)].into_iter().collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn test_schedule_keeps_events_in_order() {
+    let mut schedule = Schedule::new();
+    assert!(schedule.insert(Clock::new(18, 0)));
+    assert!(schedule.insert(Clock::new(7, 30)));
+    assert!(!schedule.insert(Clock::new(31, 30)));
+
+    assert_eq!(schedule.len(), 2);
+    assert!(schedule.contains(&Clock::new(7, 30)));
+    assert_eq!(
+        schedule.iter().collect::<Vec<_>>(),
+        vec![&Clock::new(7, 30), &Clock::new(18, 0)]
+    );
+
+    assert!(schedule.remove(&Clock::new(7, 30)));
+    assert!(!schedule.remove(&Clock::new(7, 30)));
+    assert_eq!(schedule.len(), 1);
+}
+
+#[test]
+fn test_next_event_after() {
+    let schedule: Schedule = [Clock::new(7, 0), Clock::new(12, 0), Clock::new(19, 0)]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        schedule.next_after(Clock::new(6, 0)),
+        Some(Clock::new(7, 0))
+    );
+    assert_eq!(
+        schedule.next_after(Clock::new(7, 0)),
+        Some(Clock::new(12, 0))
+    );
+    assert_eq!(
+        schedule.next_after(Clock::new(19, 0)),
+        Some(Clock::new(7, 0))
+    );
+    assert_eq!(
+        schedule.next_after(Clock::new(23, 0)),
+        Some(Clock::new(7, 0))
+    );
+}
+
+#[test]
+fn test_previous_event_before() {
+    let schedule: Schedule = [Clock::new(7, 0), Clock::new(12, 0), Clock::new(19, 0)]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        schedule.previous_before(Clock::new(13, 0)),
+        Some(Clock::new(12, 0))
+    );
+    assert_eq!(
+        schedule.previous_before(Clock::new(12, 0)),
+        Some(Clock::new(7, 0))
+    );
+    assert_eq!(
+        schedule.previous_before(Clock::new(7, 0)),
+        Some(Clock::new(19, 0))
+    );
+    assert_eq!(
+        schedule.previous_before(Clock::new(0, 0)),
+        Some(Clock::new(19, 0))
+    );
+}
+
+#[test]
+fn test_single_event_wraps_to_itself() {
+    let schedule: Schedule = [Clock::new(8, 0)].into_iter().collect();
+    assert_eq!(
+        schedule.next_after(Clock::new(8, 0)),
+        Some(Clock::new(8, 0))
+    );
+    assert_eq!(
+        schedule.previous_before(Clock::new(8, 0)),
+        Some(Clock::new(8, 0))
+    );
+}
+
+#[test]
+fn test_empty_schedule() {
+    let schedule = Schedule::new();
+    assert!(schedule.is_empty());
+    assert_eq!(schedule.next_after(Clock::new(8, 0)), None);
+    assert_eq!(schedule.previous_before(Clock::new(8, 0)), None);
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "serde")]
+
+use clock::Clock;
+use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+#[test]
+fn test_human_readable_formats_use_hh_mm() {
+    assert_tokens(&Clock::new(7, 5).readable(), &[Token::Str("07:05")]);
+    assert_eq!(
+        serde_json::to_string(&Clock::new(-1, 15)).unwrap(),
+        "\"23:15\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Clock>("\"23:15\"").unwrap(),
+        Clock::new(23, 15)
+    );
+}
+
+#[test]
+fn test_binary_formats_use_the_minute_of_the_day() {
+    assert_tokens(&Clock::new(7, 5).compact(), &[Token::U16(425)]);
+    assert_tokens(&Clock::new(23, 59).compact(), &[Token::U16(1439)]);
+}
+
+#[test]
+fn test_rejects_out_of_range_strings() {
+    assert!(serde_json::from_str::<Clock>("\"24:00\"").is_err());
+    assert!(serde_json::from_str::<Clock>("\"12:60\"").is_err());
+}
+
+#[test]
+fn test_rejects_other_string_formats() {
+    for time in [
+        "\"7:05\"",
+        "\"7:05 pm\"",
+        "\"T07:05\"",
+        "\" 7:05\"",
+        "\"07-05\"",
+    ] {
+        assert!(serde_json::from_str::<Clock>(time).is_err(), "{}", time);
+    }
+}
+
+#[test]
+fn test_rejects_out_of_range_minutes() {
+    assert_de_tokens_error::<serde_test::Compact<Clock>>(
+        &[Token::U16(1440)],
+        "invalid value: integer `1440`, expected a time as \"HH:MM\" or a minute of the day below 1440",
+    );
+}
@@ -0,0 +1,71 @@
+use clock::{Clock, DateTimeless, UtcOffset, ZonedClock};
+
+#[test]
+fn test_offsets() {
+    assert_eq!(UtcOffset::east(5, 45).unwrap().as_minutes(), 345);
+    assert_eq!(UtcOffset::west(3, 30).unwrap().as_minutes(), -210);
+    assert_eq!(UtcOffset::east(14, 0).unwrap().to_string(), "+14:00");
+    assert_eq!(UtcOffset::west(9, 30).unwrap().to_string(), "-09:30");
+    assert_eq!(UtcOffset::UTC.to_string(), "+00:00");
+}
+
+#[test]
+fn test_offsets_out_of_range() {
+    assert_eq!(UtcOffset::east(14, 1), None);
+    assert_eq!(UtcOffset::west(12, 1), None);
+    assert_eq!(UtcOffset::east(5, 60), None);
+    assert_eq!(UtcOffset::west(-3, 0), None);
+    assert_eq!(UtcOffset::from_minutes(-721), None);
+}
+
+#[test]
+fn test_format_with_offset() {
+    let kathmandu = UtcOffset::east(5, 45).unwrap();
+    let clock = ZonedClock::new(Clock::new(14, 30), kathmandu);
+    assert_eq!(clock.to_string(), "14:30+05:45");
+}
+
+#[test]
+fn test_to_utc() {
+    let kathmandu = UtcOffset::east(5, 45).unwrap();
+    assert_eq!(
+        ZonedClock::new(Clock::new(14, 30), kathmandu).to_utc(),
+        Clock::new(8, 45)
+    );
+
+    let st_johns = UtcOffset::west(3, 30).unwrap();
+    assert_eq!(
+        ZonedClock::new(Clock::new(22, 0), st_johns).to_utc(),
+        Clock::new(1, 30)
+    );
+}
+
+#[test]
+fn test_convert_between_zones() {
+    let kathmandu = UtcOffset::east(5, 45).unwrap();
+    let adelaide = UtcOffset::east(9, 30).unwrap();
+    let meeting = ZonedClock::new(Clock::new(9, 0), kathmandu);
+
+    let local = meeting.to_offset(adelaide);
+    assert_eq!(local.to_string(), "12:45+09:30");
+    assert!(local.same_instant(&meeting));
+    assert_ne!(local, meeting);
+}
+
+#[test]
+fn test_conversion_reports_day_changes() {
+    let tokyo = UtcOffset::east(9, 0).unwrap();
+    let honolulu = UtcOffset::west(10, 0).unwrap();
+
+    let call = ZonedClock::new(Clock::new(8, 0), tokyo);
+    assert_eq!(
+        call.to_offset_carrying_days(honolulu),
+        DateTimeless::new(-1, Clock::new(13, 0))
+    );
+
+    let call = ZonedClock::new(Clock::new(20, 0), honolulu);
+    assert_eq!(
+        call.to_offset_carrying_days(tokyo),
+        DateTimeless::new(1, Clock::new(15, 0))
+    );
+}
@@ -0,0 +1,161 @@
+use crate::graph::graph_items::edge::Edge;
+use crate::graph::graph_items::node::Node;
+use crate::graph::graph_items::subgraph::Subgraph;
+use crate::graph::Graph;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+const KEYWORDS: [&str; 6] = ["node", "edge", "graph", "digraph", "subgraph", "strict"];
+
+/// Writes the graph as DOT text, e.g. `graph { a -- b [color=blue]; }`.
+impl Display for Graph {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.strict {
+            write!(f, "strict ")?;
+        }
+        writeln!(f, "{} {{", self.kind.keyword())?;
+        let body = Body {
+            attrs: &self.attrs,
+            nodes: &self.nodes,
+            edges: &self.edges,
+            subgraphs: &self.subgraphs,
+        };
+        body.write(f, self.kind.edge_op(), 1)?;
+        write!(f, "}}")
+    }
+}
+
+/// The statements shared by graphs and subgraphs.
+struct Body<'a> {
+    attrs: &'a HashMap<String, String>,
+    nodes: &'a [Node],
+    edges: &'a [Edge],
+    subgraphs: &'a [Subgraph],
+}
+
+impl Body<'_> {
+    fn write(&self, f: &mut Formatter, edge_op: &str, depth: usize) -> fmt::Result {
+        let indent = "    ".repeat(depth);
+        for (key, value) in sorted(self.attrs) {
+            writeln!(f, "{}{}={};", indent, Id(key), Id(value))?;
+        }
+        for node in self.nodes {
+            writeln!(f, "{}{}{};", indent, Id(&node.id), AttrList(&node.attrs))?;
+        }
+        for subgraph in self.subgraphs {
+            write!(f, "{}subgraph ", indent)?;
+            if !subgraph.id.is_empty() {
+                write!(f, "{} ", Id(&subgraph.id))?;
+            }
+            writeln!(f, "{{")?;
+            let body = Body {
+                attrs: &subgraph.attrs,
+                nodes: &subgraph.nodes,
+                edges: &subgraph.edges,
+                subgraphs: &subgraph.subgraphs,
+            };
+            body.write(f, edge_op, depth + 1)?;
+            writeln!(f, "{}}}", indent)?;
+        }
+        for edge in self.edges {
+            writeln!(
+                f,
+                "{}{} {} {}{};",
+                indent,
+                Id(edge.from()),
+                edge_op,
+                Id(edge.to()),
+                AttrList(edge.attrs())
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn sorted(attrs: &HashMap<String, String>) -> Vec<(&String, &String)> {
+    let mut attrs: Vec<_> = attrs.iter().collect();
+    attrs.sort();
+    attrs
+}
+
+/// ` [key=value, ...]`, or nothing when there are no attributes.
+struct AttrList<'a>(&'a HashMap<String, String>);
+
+impl Display for AttrList<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        write!(f, " [")?;
+        for (i, (key, value)) in sorted(self.0).into_iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}={}", Id(key), Id(value))?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// An id or attribute value, quoted unless it is a plain identifier or numeral. Backslashes
+/// are left for Graphviz to interpret, so `\n` in a label stays a line break.
+///
+/// DOT reads `\\` as a pair, so an odd run of backslashes can't come right before a quote, a
+/// newline or the end of a value. Such a run is normalised by adding one backslash; every
+/// other value, including anything parsed from DOT, is written so it parses back unchanged.
+struct Id<'a>(&'a str);
+
+impl Id<'_> {
+    fn is_bare(&self) -> bool {
+        let id = self.0;
+        if KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(id)) {
+            return false;
+        }
+        is_identifier(id) || is_numeral(id)
+    }
+}
+
+impl Display for Id<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_bare() {
+            return write!(f, "{}", self.0);
+        }
+        write!(f, "\"")?;
+        let mut chars = self.0.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => write!(f, "\\\"")?,
+                '\\' if chars.peek() == Some(&'\\') => {
+                    chars.next();
+                    write!(f, "\\\\")?;
+                }
+                '\\' if matches!(chars.peek(), None | Some('"') | Some('\n')) => write!(f, "\\\\")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        write!(f, "\"")
+    }
+}
+
+fn is_identifier(id: &str) -> bool {
+    let is_start = |c: char| c.is_ascii_alphabetic() || c == '_' || !c.is_ascii();
+    match id.chars().next() {
+        Some(c) if is_start(c) => id.chars().all(|c| is_start(c) || c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+fn is_numeral(id: &str) -> bool {
+    let digits = id.strip_prefix('-').unwrap_or(id);
+    let (whole, fraction) = match digits.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (digits, None),
+    };
+    let all_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    match fraction {
+        None => !whole.is_empty() && all_digits(whole),
+        Some(fraction) => {
+            (!whole.is_empty() || !fraction.is_empty()) && all_digits(whole) && all_digits(fraction)
+        }
+    }
+}
@@ -8,28 +8,81 @@ macro_rules! with_attrs {
     }};
 }
 
+mod dot;
+mod parse;
+mod validate;
+
+pub use parse::{ParseError, ParseErrorKind};
+pub use validate::{Diagnostic, Element};
+
 pub mod graph {
     use crate::graph::graph_items::edge::Edge;
     use crate::graph::graph_items::node::Node;
+    use crate::graph::graph_items::subgraph::Subgraph;
     use maplit::hashmap;
     use std::collections::HashMap;
 
+    /// Whether edges are directed (`a -> b`) or not (`a -- b`).
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    pub enum Kind {
+        #[default]
+        Graph,
+        Digraph,
+    }
+
+    impl Kind {
+        pub fn keyword(self) -> &'static str {
+            match self {
+                Kind::Graph => "graph",
+                Kind::Digraph => "digraph",
+            }
+        }
+
+        pub fn edge_op(self) -> &'static str {
+            match self {
+                Kind::Graph => "--",
+                Kind::Digraph => "->",
+            }
+        }
+    }
+
     #[derive(Debug, PartialEq, Clone)]
     pub struct Graph {
+        pub kind: Kind,
+        /// A strict graph has at most one edge between any two nodes.
+        pub strict: bool,
         pub nodes: Vec<Node>,
         pub edges: Vec<Edge>,
         pub attrs: HashMap<String, String>,
+        pub subgraphs: Vec<Subgraph>,
     }
 
     impl Graph {
         pub fn new() -> Self {
             Graph {
+                kind: Kind::Graph,
+                strict: false,
                 nodes: vec![],
                 edges: vec![],
                 attrs: hashmap! {},
+                subgraphs: vec![],
             }
         }
 
+        pub fn with_kind(&self, kind: Kind) -> Self {
+            let mut graph = self.clone();
+            graph.kind = kind;
+            graph.merge_duplicate_edges();
+            graph
+        }
+
+        pub fn with_strict(&self, strict: bool) -> Self {
+            let mut graph = self.clone();
+            graph.strict = strict;
+            graph.merge_duplicate_edges();
+            graph
+        }
+
         pub fn with_nodes(&self, nodes: &[Node]) -> Self {
             let nodes = nodes.to_vec();
             let mut graph = self.clone();
@@ -41,11 +94,65 @@ pub mod graph {
             let edges = edges.to_vec();
             let mut graph = self.clone();
             graph.edges = edges;
+            graph.merge_duplicate_edges();
+            graph
+        }
+
+        pub fn with_subgraphs(&self, subgraphs: &[Subgraph]) -> Self {
+            let mut graph = self.clone();
+            graph.subgraphs = subgraphs.to_vec();
+            graph.merge_duplicate_edges();
             graph
         }
 
+        /// The first edge joining `from` to `to`, searching subgraphs too; either way round in
+        /// an undirected graph.
+        pub fn edge(&self, from: &str, to: &str) -> Option<Edge> {
+            let joins = |e: &Edge| joins(self.kind, e, from, to);
+            self.edges
+                .iter()
+                .find(|e| joins(e))
+                .or_else(|| self.subgraphs.iter().find_map(|s| s.find_edge(&joins)))
+                .cloned()
+        }
+
+        /// The edge lists of the graph and every nested subgraph, top-level edges first.
+        fn edge_lists_mut(&mut self) -> Vec<&mut Vec<Edge>> {
+            let mut lists = vec![&mut self.edges];
+            for subgraph in &mut self.subgraphs {
+                lists.extend(subgraph.edge_lists_mut());
+            }
+            lists
+        }
+
+        /// A strict graph keeps one edge per pair of nodes across all its subgraphs, so later
+        /// duplicates are merged into the first.
+        fn merge_duplicate_edges(&mut self) {
+            if !self.strict {
+                return;
+            }
+            let kind = self.kind;
+            let mut lists = self.edge_lists_mut();
+            let taken: Vec<Vec<Edge>> = lists.iter_mut().map(|l| std::mem::take(*l)).collect();
+            for (target, edges) in taken.into_iter().enumerate() {
+                for edge in edges {
+                    add_strict_edge(kind, &mut lists, target, edge);
+                }
+            }
+        }
+
+        /// Searches subgraphs too, so nodes declared inside clusters are found.
         pub fn node(&self, id: &str) -> Option<Node> {
-            self.nodes.iter().find(|n| n.id == id).cloned()
+            self.nodes
+                .iter()
+                .find(|n| n.id == id)
+                .cloned()
+                .or_else(|| self.subgraphs.iter().find_map(|s| s.node(id)))
+        }
+
+        /// Searches nested subgraphs too.
+        pub fn subgraph(&self, id: &str) -> Option<Subgraph> {
+            self.subgraphs.iter().find_map(|s| s.subgraph(id))
         }
 
         pub fn attr(&self, key: &str) -> Option<&str> {
@@ -61,6 +168,37 @@ pub mod graph {
         }
     }
 
+    fn joins(kind: Kind, edge: &Edge, from: &str, to: &str) -> bool {
+        (edge.from() == from && edge.to() == to)
+            || (kind == Kind::Graph && edge.from() == to && edge.to() == from)
+    }
+
+    /// Pushes `edge` onto `lists[target]`, or merges its attributes into an edge joining the
+    /// same nodes in any of `lists`.
+    pub(crate) fn add_strict_edge(
+        kind: Kind,
+        lists: &mut [&mut Vec<Edge>],
+        target: usize,
+        edge: Edge,
+    ) {
+        let existing = lists.iter().enumerate().find_map(|(i, list)| {
+            list.iter()
+                .position(|e| joins(kind, e, edge.from(), edge.to()))
+                .map(|j| (i, j))
+        });
+        match existing {
+            Some((i, j)) => {
+                let attrs: Vec<(&str, &str)> = edge
+                    .attrs()
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect();
+                lists[i][j] = lists[i][j].with_attrs(&attrs);
+            }
+            None => lists[target].push(edge),
+        }
+    }
+
     impl Default for Graph {
         fn default() -> Self {
             Self::new()
@@ -87,6 +225,18 @@ pub mod graph {
                         attrs: hashmap! {},
                     }
                 }
+                pub fn from(&self) -> &str {
+                    &self.from
+                }
+
+                pub fn to(&self) -> &str {
+                    &self.to
+                }
+
+                pub fn attrs(&self) -> &HashMap<String, String> {
+                    &self.attrs
+                }
+
                 pub fn attr(&self, key: &str) -> Option<&str> {
                     self.attrs.get(key).map(|s| s.as_str())
                 }
@@ -132,5 +282,101 @@ pub mod graph {
                 }
             }
         }
+
+        pub mod subgraph {
+            use crate::graph::graph_items::edge::Edge;
+            use crate::graph::graph_items::node::Node;
+            use maplit::hashmap;
+            use std::collections::HashMap;
+
+            /// A `subgraph id { ... }` block. Anonymous `{ ... }` blocks have an empty id.
+            #[derive(Debug, PartialEq, Clone)]
+            pub struct Subgraph {
+                pub id: String,
+                pub nodes: Vec<Node>,
+                pub edges: Vec<Edge>,
+                pub attrs: HashMap<String, String>,
+                pub subgraphs: Vec<Subgraph>,
+            }
+
+            impl Subgraph {
+                pub fn new(id: &str) -> Self {
+                    Self {
+                        id: id.to_string(),
+                        nodes: vec![],
+                        edges: vec![],
+                        attrs: hashmap! {},
+                        subgraphs: vec![],
+                    }
+                }
+
+                pub fn with_nodes(&self, nodes: &[Node]) -> Self {
+                    let mut subgraph = self.clone();
+                    subgraph.nodes = nodes.to_vec();
+                    subgraph
+                }
+
+                pub fn with_edges(&self, edges: &[Edge]) -> Self {
+                    let mut subgraph = self.clone();
+                    subgraph.edges = edges.to_vec();
+                    subgraph
+                }
+
+                pub fn with_subgraphs(&self, subgraphs: &[Subgraph]) -> Self {
+                    let mut subgraph = self.clone();
+                    subgraph.subgraphs = subgraphs.to_vec();
+                    subgraph
+                }
+
+                /// Graphviz draws subgraphs named `cluster...` as a box around their nodes.
+                pub fn is_cluster(&self) -> bool {
+                    self.id.starts_with("cluster")
+                }
+
+                /// Searches nested subgraphs too.
+                pub fn node(&self, id: &str) -> Option<Node> {
+                    self.nodes
+                        .iter()
+                        .find(|n| n.id == id)
+                        .cloned()
+                        .or_else(|| self.subgraphs.iter().find_map(|s| s.node(id)))
+                }
+
+                /// This subgraph or a nested one with the given id.
+                pub fn subgraph(&self, id: &str) -> Option<Subgraph> {
+                    if self.id == id {
+                        return Some(self.clone());
+                    }
+                    self.subgraphs.iter().find_map(|s| s.subgraph(id))
+                }
+
+                pub(crate) fn edge_lists_mut(&mut self) -> Vec<&mut Vec<Edge>> {
+                    let mut lists = vec![&mut self.edges];
+                    for subgraph in &mut self.subgraphs {
+                        lists.extend(subgraph.edge_lists_mut());
+                    }
+                    lists
+                }
+
+                pub(crate) fn find_edge(&self, matches: &dyn Fn(&Edge) -> bool) -> Option<&Edge> {
+                    self.edges
+                        .iter()
+                        .find(|e| matches(e))
+                        .or_else(|| self.subgraphs.iter().find_map(|s| s.find_edge(matches)))
+                }
+
+                pub fn attr(&self, key: &str) -> Option<&str> {
+                    self.attrs.get(key).map(|s| s.as_str())
+                }
+
+                fn set_attr(&mut self, key: &str, value: &str) {
+                    self.attrs.insert(key.into(), value.into());
+                }
+
+                pub fn with_attrs(&self, attrs: &[(&str, &str)]) -> Self {
+                    with_attrs!(self, attrs)
+                }
+            }
+        }
     }
 }
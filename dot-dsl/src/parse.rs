@@ -0,0 +1,612 @@
+use crate::graph::graph_items::edge::Edge;
+use crate::graph::graph_items::node::Node;
+use crate::graph::graph_items::subgraph::Subgraph;
+use crate::graph::{add_strict_edge, Graph, Kind};
+use std::str::FromStr;
+
+/// Where parsing stopped, with 1-based line and column.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseErrorKind {
+    UnexpectedChar(char),
+    UnterminatedString,
+    UnterminatedComment,
+    UnexpectedToken {
+        expected: &'static str,
+        found: String,
+    },
+    UnexpectedEnd {
+        expected: &'static str,
+    },
+    /// `->` in a `graph`, or `--` in a `digraph`.
+    MismatchedEdgeOp(&'static str),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    Id(String),
+    Quoted(String),
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Semicolon,
+    Comma,
+    Equals,
+    Colon,
+    Plus,
+    EdgeOp(&'static str),
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Id(id) => id.clone(),
+            Token::Quoted(id) => format!("\"{}\"", id),
+            Token::LBrace => "{".into(),
+            Token::RBrace => "}".into(),
+            Token::LBracket => "[".into(),
+            Token::RBracket => "]".into(),
+            Token::Semicolon => ";".into(),
+            Token::Comma => ",".into(),
+            Token::Equals => "=".into(),
+            Token::Colon => ":".into(),
+            Token::Plus => "+".into(),
+            Token::EdgeOp(op) => op.to_string(),
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Id(id) if id.eq_ignore_ascii_case(keyword))
+    }
+
+    fn is_any_keyword(&self) -> bool {
+        ["node", "edge", "graph", "digraph", "subgraph", "strict"]
+            .iter()
+            .any(|k| self.is_keyword(k))
+    }
+}
+
+struct Spanned {
+    token: Token,
+    line: usize,
+    column: usize,
+}
+
+struct Lexer {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Lexer {
+    fn new(source: &str) -> Self {
+        Lexer {
+            chars: source.chars().collect(),
+            pos: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek_at(0)?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, line: usize, column: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError { line, column, kind }
+    }
+
+    fn tokenize(mut self) -> Result<(Vec<Spanned>, (usize, usize)), ParseError> {
+        let mut tokens = vec![];
+        while let Some(c) = self.peek_at(0) {
+            let (line, column) = (self.line, self.column);
+            let token = match c {
+                c if c.is_whitespace() => {
+                    self.bump();
+                    continue;
+                }
+                '#' if column == 1 => {
+                    self.skip_line();
+                    continue;
+                }
+                '/' if self.peek_at(1) == Some('/') => {
+                    self.skip_line();
+                    continue;
+                }
+                '/' if self.peek_at(1) == Some('*') => {
+                    self.skip_block_comment(line, column)?;
+                    continue;
+                }
+                '"' => self.quoted(line, column)?,
+                '-' if self.peek_at(1) == Some('-') => self.op("--"),
+                '-' if self.peek_at(1) == Some('>') => self.op("->"),
+                c if c == '-' || c == '.' || c.is_ascii_digit() => self.numeral(line, column)?,
+                c if c.is_alphabetic() || c == '_' || !c.is_ascii() => self.identifier(),
+                _ => {
+                    self.bump();
+                    match c {
+                        '{' => Token::LBrace,
+                        '}' => Token::RBrace,
+                        '[' => Token::LBracket,
+                        ']' => Token::RBracket,
+                        ';' => Token::Semicolon,
+                        ',' => Token::Comma,
+                        '=' => Token::Equals,
+                        ':' => Token::Colon,
+                        '+' => Token::Plus,
+                        c => {
+                            return Err(self.error(line, column, ParseErrorKind::UnexpectedChar(c)))
+                        }
+                    }
+                }
+            };
+            tokens.push(Spanned {
+                token,
+                line,
+                column,
+            });
+        }
+        Ok((tokens, (self.line, self.column)))
+    }
+
+    fn skip_line(&mut self) {
+        while let Some(c) = self.bump() {
+            if c == '\n' {
+                break;
+            }
+        }
+    }
+
+    fn skip_block_comment(&mut self, line: usize, column: usize) -> Result<(), ParseError> {
+        self.bump();
+        self.bump();
+        loop {
+            match self.bump() {
+                Some('*') if self.peek_at(0) == Some('/') => {
+                    self.bump();
+                    return Ok(());
+                }
+                Some(_) => {}
+                None => return Err(self.error(line, column, ParseErrorKind::UnterminatedComment)),
+            }
+        }
+    }
+
+    fn op(&mut self, op: &'static str) -> Token {
+        self.bump();
+        self.bump();
+        Token::EdgeOp(op)
+    }
+
+    /// Unescapes `\"` and joins escaped newlines; other backslashes, including `\\`, are kept
+    /// for Graphviz to interpret, e.g. `\n` in labels. This is how Graphviz reads quoted ids,
+    /// and the writer is its inverse.
+    fn quoted(&mut self, line: usize, column: usize) -> Result<Token, ParseError> {
+        self.bump();
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(Token::Quoted(text)),
+                Some('\\') if self.peek_at(0) == Some('"') => {
+                    self.bump();
+                    text.push('"');
+                }
+                Some('\\') if self.peek_at(0) == Some('\\') => {
+                    self.bump();
+                    text.push_str("\\\\");
+                }
+                Some('\\') if self.peek_at(0) == Some('\n') => {
+                    self.bump();
+                }
+                Some(c) => text.push(c),
+                None => return Err(self.error(line, column, ParseErrorKind::UnterminatedString)),
+            }
+        }
+    }
+
+    fn numeral(&mut self, line: usize, column: usize) -> Result<Token, ParseError> {
+        let mut text = String::new();
+        if self.peek_at(0) == Some('-') {
+            self.bump();
+            text.push('-');
+        }
+        let mut seen_dot = false;
+        while let Some(c) = self.peek_at(0) {
+            if c.is_ascii_digit() || (c == '.' && !seen_dot) {
+                seen_dot |= c == '.';
+                self.bump();
+                text.push(c);
+            } else {
+                break;
+            }
+        }
+        if !text.chars().any(|c| c.is_ascii_digit()) {
+            let c = text.chars().next().unwrap_or('-');
+            return Err(self.error(line, column, ParseErrorKind::UnexpectedChar(c)));
+        }
+        Ok(Token::Id(text))
+    }
+
+    fn identifier(&mut self) -> Token {
+        let mut text = String::new();
+        while let Some(c) = self.peek_at(0) {
+            if c.is_alphanumeric() || c == '_' || !c.is_ascii() {
+                self.bump();
+                text.push(c);
+            } else {
+                break;
+            }
+        }
+        Token::Id(text)
+    }
+}
+
+struct Parser {
+    tokens: Vec<Spanned>,
+    pos: usize,
+    end: (usize, usize),
+    graph: Graph,
+    /// The root statements followed by each open subgraph, innermost last.
+    scopes: Vec<Scope>,
+}
+
+/// Statements collected for the graph or a subgraph, with the defaults in effect there.
+struct Scope {
+    body: Subgraph,
+    node_defaults: Vec<(String, String)>,
+    edge_defaults: Vec<(String, String)>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|s| &s.token)
+    }
+
+    fn unexpected(&self, expected: &'static str) -> ParseError {
+        match self.tokens.get(self.pos) {
+            Some(spanned) => ParseError {
+                line: spanned.line,
+                column: spanned.column,
+                kind: ParseErrorKind::UnexpectedToken {
+                    expected,
+                    found: spanned.token.describe(),
+                },
+            },
+            None => ParseError {
+                line: self.end.0,
+                column: self.end.1,
+                kind: ParseErrorKind::UnexpectedEnd { expected },
+            },
+        }
+    }
+
+    fn expect(&mut self, token: Token, expected: &'static str) -> Result<(), ParseError> {
+        if self.peek() != Some(&token) {
+            return Err(self.unexpected(expected));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn eat(&mut self, token: Token) -> bool {
+        let found = self.peek() == Some(&token);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn at_keyword(&self, keyword: &str) -> bool {
+        self.peek().is_some_and(|t| t.is_keyword(keyword))
+    }
+
+    fn at_id(&self) -> bool {
+        match self.peek() {
+            Some(Token::Quoted(_)) => true,
+            Some(token @ Token::Id(_)) => !token.is_any_keyword(),
+            _ => false,
+        }
+    }
+
+    /// An id, joining `"a" + "b"` concatenations.
+    fn id(&mut self) -> Result<String, ParseError> {
+        if !self.at_id() {
+            return Err(self.unexpected("an id"));
+        }
+        let mut id = match self.peek() {
+            Some(Token::Id(id) | Token::Quoted(id)) => id.clone(),
+            _ => unreachable!(),
+        };
+        let quoted = matches!(self.peek(), Some(Token::Quoted(_)));
+        self.pos += 1;
+        while quoted && self.eat(Token::Plus) {
+            match self.peek() {
+                Some(Token::Quoted(more)) => id.push_str(more),
+                _ => return Err(self.unexpected("a quoted string")),
+            }
+            self.pos += 1;
+        }
+        Ok(id)
+    }
+
+    fn graph(mut self) -> Result<Graph, ParseError> {
+        if self.at_keyword("strict") {
+            self.pos += 1;
+            self.graph.strict = true;
+        }
+        if self.at_keyword("graph") {
+            self.graph.kind = Kind::Graph;
+        } else if self.at_keyword("digraph") {
+            self.graph.kind = Kind::Digraph;
+        } else {
+            return Err(self.unexpected("graph or digraph"));
+        }
+        self.pos += 1;
+        if self.at_id() {
+            self.id()?;
+        }
+        self.block()?;
+        if self.peek().is_some() {
+            return Err(self.unexpected("end of input"));
+        }
+        let root = self.scopes.pop().expect("root scope").body;
+        self.graph.attrs = root.attrs;
+        self.graph.nodes = root.nodes;
+        self.graph.subgraphs = root.subgraphs;
+        self.graph.edges = root.edges;
+        Ok(self.graph)
+    }
+
+    fn block(&mut self) -> Result<(), ParseError> {
+        self.expect(Token::LBrace, "{")?;
+        while !self.eat(Token::RBrace) {
+            self.statement()?;
+            self.eat(Token::Semicolon);
+        }
+        Ok(())
+    }
+
+    fn scope(&mut self) -> &mut Scope {
+        self.scopes.last_mut().expect("root scope")
+    }
+
+    /// `subgraph [id] { ... }` or an anonymous `{ ... }`; defaults set inside stay inside.
+    /// Returns the ids of every node in the subgraph, for edges that use it as an endpoint.
+    fn subgraph(&mut self) -> Result<Vec<String>, ParseError> {
+        let mut id = String::new();
+        if self.at_keyword("subgraph") {
+            self.pos += 1;
+            if self.at_id() {
+                id = self.id()?;
+            }
+        }
+        let scope = Scope {
+            body: Subgraph::new(&id),
+            node_defaults: self.scope().node_defaults.clone(),
+            edge_defaults: self.scope().edge_defaults.clone(),
+        };
+        self.scopes.push(scope);
+        self.block()?;
+        let subgraph = self.scopes.pop().expect("subgraph scope").body;
+        let mut ids = vec![];
+        node_ids(&subgraph, &mut ids);
+        self.scope().body.subgraphs.push(subgraph);
+        Ok(ids)
+    }
+
+    fn statement(&mut self) -> Result<(), ParseError> {
+        if self.at_keyword("graph") {
+            self.pos += 1;
+            let attrs = self.attr_list(true)?;
+            self.scope().body.attrs.extend(attrs);
+        } else if self.at_keyword("node") {
+            self.pos += 1;
+            let attrs = self.attr_list(true)?;
+            self.scope().node_defaults.extend(attrs);
+        } else if self.at_keyword("edge") {
+            self.pos += 1;
+            let attrs = self.attr_list(true)?;
+            self.scope().edge_defaults.extend(attrs);
+        } else if self.at_id() {
+            let id = self.id()?;
+            if self.eat(Token::Equals) {
+                let value = self.id()?;
+                self.scope().body.attrs.insert(id, value);
+                return Ok(());
+            }
+            let port = self.port()?;
+            if matches!(self.peek(), Some(Token::EdgeOp(_))) {
+                self.add_node(&id, vec![]);
+                self.edges(vec![(id, port)])?;
+            } else {
+                // like Graphviz, a port on a node statement has no effect
+                let attrs = self.attr_list(false)?;
+                self.add_node(&id, attrs);
+            }
+        } else if self.at_subgraph() {
+            let ids = self.subgraph()?;
+            if matches!(self.peek(), Some(Token::EdgeOp(_))) {
+                self.edges(ids.into_iter().map(|id| (id, None)).collect())?;
+            }
+        } else {
+            return Err(self.unexpected("a statement or }"));
+        }
+        Ok(())
+    }
+
+    /// The `:port` or `:port:compass` after a node id, if any.
+    fn port(&mut self) -> Result<Option<String>, ParseError> {
+        if !self.eat(Token::Colon) {
+            return Ok(None);
+        }
+        let mut port = self.id()?;
+        if self.eat(Token::Colon) {
+            port.push(':');
+            port.push_str(&self.id()?);
+        }
+        Ok(Some(port))
+    }
+
+    fn at_subgraph(&self) -> bool {
+        self.at_keyword("subgraph") || self.peek() == Some(&Token::LBrace)
+    }
+
+    /// An edge chain whose operands are a node with an optional port, or a subgraph standing
+    /// for all of its nodes. Endpoint ports become the `tailport` and `headport` attributes of
+    /// each edge, the way Graphviz stores them.
+    fn edges(&mut self, first: Vec<(String, Option<String>)>) -> Result<(), ParseError> {
+        let mut operands = vec![first];
+        while let Some(Token::EdgeOp(op)) = self.peek() {
+            if *op != self.graph.kind.edge_op() {
+                let spanned = &self.tokens[self.pos];
+                return Err(ParseError {
+                    line: spanned.line,
+                    column: spanned.column,
+                    kind: ParseErrorKind::MismatchedEdgeOp(op),
+                });
+            }
+            self.pos += 1;
+            if self.at_subgraph() {
+                let ids = self.subgraph()?;
+                operands.push(ids.into_iter().map(|id| (id, None)).collect());
+            } else {
+                let id = self.id()?;
+                let port = self.port()?;
+                self.add_node(&id, vec![]);
+                operands.push(vec![(id, port)]);
+            }
+        }
+        let defaults = self.scope().edge_defaults.clone();
+        let attrs = self.attr_list(false)?;
+        for pair in operands.windows(2) {
+            for ((from, tail), (to, head)) in pair[0]
+                .iter()
+                .flat_map(|t| pair[1].iter().map(move |h| (t, h)))
+            {
+                let mut edge_attrs: Vec<(&str, &str)> = defaults
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect();
+                if let Some(tail) = tail {
+                    edge_attrs.push(("tailport", tail));
+                }
+                if let Some(head) = head {
+                    edge_attrs.push(("headport", head));
+                }
+                edge_attrs.extend(attrs.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+                let edge = Edge::new(from, to).with_attrs(&edge_attrs);
+                self.add_edge(edge);
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the edge to the current scope; a strict graph merges it into any earlier edge
+    /// joining the same nodes, wherever that was declared.
+    fn add_edge(&mut self, edge: Edge) {
+        if !self.graph.strict {
+            self.scope().body.edges.push(edge);
+            return;
+        }
+        let (current, outer) = self.scopes.split_last_mut().expect("root scope");
+        let mut lists: Vec<&mut Vec<Edge>> = outer
+            .iter_mut()
+            .flat_map(|s| s.body.edge_lists_mut())
+            .collect();
+        let target = lists.len();
+        lists.extend(current.body.edge_lists_mut());
+        add_strict_edge(self.graph.kind, &mut lists, target, edge);
+    }
+
+    /// Adds the node to the current scope. It takes the `node [...]` defaults only when seen for
+    /// the first time anywhere in the graph; later statements add to its attributes.
+    fn add_node(&mut self, id: &str, attrs: Vec<(String, String)>) {
+        let is_new = self.scopes.iter().all(|s| s.body.node(id).is_none());
+        let scope = self.scope();
+        let index = match scope.body.nodes.iter().position(|n| n.id == id) {
+            Some(index) => index,
+            None => {
+                let mut node = Node::new(id);
+                if is_new {
+                    node.attrs.extend(scope.node_defaults.iter().cloned());
+                }
+                scope.body.nodes.push(node);
+                scope.body.nodes.len() - 1
+            }
+        };
+        scope.body.nodes[index].attrs.extend(attrs);
+    }
+
+    /// One or more `[key=value, ...]` blocks.
+    fn attr_list(&mut self, required: bool) -> Result<Vec<(String, String)>, ParseError> {
+        if required && self.peek() != Some(&Token::LBracket) {
+            return Err(self.unexpected("["));
+        }
+        let mut attrs = vec![];
+        while self.eat(Token::LBracket) {
+            while !self.eat(Token::RBracket) {
+                let key = self.id()?;
+                self.expect(Token::Equals, "=")?;
+                attrs.push((key, self.id()?));
+                if !self.eat(Token::Comma) {
+                    self.eat(Token::Semicolon);
+                }
+            }
+        }
+        Ok(attrs)
+    }
+}
+
+/// Parses a single `graph { ... }` or `digraph { ... }` from DOT source.
+/// Nodes only mentioned in edges are added to the nodes of the (sub)graph mentioning them.
+/// Ports on edge endpoints are kept as the `tailport` and `headport` edge attributes.
+impl FromStr for Graph {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (tokens, end) = Lexer::new(s).tokenize()?;
+        Parser {
+            tokens,
+            pos: 0,
+            end,
+            graph: Graph::new(),
+            scopes: vec![Scope {
+                body: Subgraph::new(""),
+                node_defaults: vec![],
+                edge_defaults: vec![],
+            }],
+        }
+        .graph()
+    }
+}
+
+fn node_ids(subgraph: &Subgraph, ids: &mut Vec<String>) {
+    for node in &subgraph.nodes {
+        if !ids.contains(&node.id) {
+            ids.push(node.id.clone());
+        }
+    }
+    for nested in &subgraph.subgraphs {
+        node_ids(nested, ids);
+    }
+}
@@ -0,0 +1,311 @@
+use crate::graph::graph_items::edge::Edge;
+use crate::graph::graph_items::node::Node;
+use crate::graph::graph_items::subgraph::Subgraph;
+use crate::graph::Graph;
+use std::collections::{HashMap, HashSet};
+
+/// The element an attribute was set on.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Element {
+    Graph,
+    Subgraph(String),
+    Node(String),
+    Edge { from: String, to: String },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Diagnostic {
+    /// An edge endpoint that is not a node of the graph or any of its subgraphs.
+    DanglingEdge {
+        from: String,
+        to: String,
+        missing: String,
+    },
+    /// A node listed more than once in the same graph or subgraph, given by `scope`.
+    DuplicateNode {
+        scope: Element,
+        id: String,
+    },
+    UnknownAttr {
+        element: Element,
+        name: String,
+    },
+}
+
+/// Graphviz attributes with the elements using them: G for graphs, S for subgraphs, C for
+/// clusters, N for nodes and E for edges.
+const ATTRIBUTES: &[(&str, &str)] = &[
+    ("_background", "G"),
+    ("area", "NC"),
+    ("arrowhead", "E"),
+    ("arrowsize", "E"),
+    ("arrowtail", "E"),
+    ("bb", "GC"),
+    ("beautify", "G"),
+    ("bgcolor", "GC"),
+    ("center", "G"),
+    ("charset", "G"),
+    ("class", "GCNE"),
+    ("cluster", "SC"),
+    ("color", "ENC"),
+    ("colorscheme", "ENCG"),
+    ("comment", "ENG"),
+    ("compound", "G"),
+    ("concentrate", "G"),
+    ("constraint", "E"),
+    ("Damping", "G"),
+    ("decorate", "E"),
+    ("defaultdist", "G"),
+    ("dim", "G"),
+    ("dimen", "G"),
+    ("dir", "E"),
+    ("diredgeconstraints", "G"),
+    ("distortion", "N"),
+    ("dpi", "G"),
+    ("edgehref", "E"),
+    ("edgetarget", "E"),
+    ("edgetooltip", "E"),
+    ("edgeURL", "E"),
+    ("epsilon", "G"),
+    ("esep", "G"),
+    ("fillcolor", "NEC"),
+    ("fixedsize", "N"),
+    ("fontcolor", "ENGC"),
+    ("fontname", "ENGC"),
+    ("fontnames", "G"),
+    ("fontpath", "G"),
+    ("fontsize", "ENGC"),
+    ("forcelabels", "G"),
+    ("gradientangle", "NCG"),
+    ("group", "N"),
+    ("head_lp", "E"),
+    ("headclip", "E"),
+    ("headhref", "E"),
+    ("headlabel", "E"),
+    ("headport", "E"),
+    ("headtarget", "E"),
+    ("headtooltip", "E"),
+    ("headURL", "E"),
+    ("height", "N"),
+    ("href", "GCNE"),
+    ("id", "GCNE"),
+    ("image", "N"),
+    ("imagepath", "G"),
+    ("imagepos", "N"),
+    ("imagescale", "N"),
+    ("inputscale", "G"),
+    ("K", "GC"),
+    ("label", "ENGC"),
+    ("label_scheme", "G"),
+    ("labelangle", "E"),
+    ("labeldistance", "E"),
+    ("labelfloat", "E"),
+    ("labelfontcolor", "E"),
+    ("labelfontname", "E"),
+    ("labelfontsize", "E"),
+    ("labelhref", "E"),
+    ("labeljust", "GC"),
+    ("labelloc", "NGC"),
+    ("labeltarget", "E"),
+    ("labeltooltip", "E"),
+    ("labelURL", "E"),
+    ("landscape", "G"),
+    ("layer", "ENC"),
+    ("layerlistsep", "G"),
+    ("layers", "G"),
+    ("layerselect", "G"),
+    ("layersep", "G"),
+    ("layout", "G"),
+    ("len", "E"),
+    ("levels", "G"),
+    ("levelsgap", "G"),
+    ("lhead", "E"),
+    ("lheight", "GC"),
+    ("linelength", "G"),
+    ("lp", "EGC"),
+    ("ltail", "E"),
+    ("lwidth", "GC"),
+    ("margin", "NCG"),
+    ("maxiter", "G"),
+    ("mclimit", "G"),
+    ("mindist", "G"),
+    ("minlen", "E"),
+    ("mode", "G"),
+    ("model", "G"),
+    ("newrank", "G"),
+    ("nodesep", "G"),
+    ("nojustify", "GCNE"),
+    ("normalize", "G"),
+    ("notranslate", "G"),
+    ("nslimit", "G"),
+    ("nslimit1", "G"),
+    ("oneblock", "G"),
+    ("ordering", "GN"),
+    ("orientation", "NG"),
+    ("outputorder", "G"),
+    ("overlap", "G"),
+    ("overlap_scaling", "G"),
+    ("overlap_shrink", "G"),
+    ("pack", "G"),
+    ("packmode", "G"),
+    ("pad", "G"),
+    ("page", "G"),
+    ("pagedir", "G"),
+    ("pencolor", "C"),
+    ("penwidth", "CNE"),
+    ("peripheries", "NC"),
+    ("pin", "N"),
+    ("pos", "EN"),
+    ("quadtree", "G"),
+    ("quantum", "G"),
+    ("rank", "S"),
+    ("rankdir", "G"),
+    ("ranksep", "G"),
+    ("ratio", "G"),
+    ("rects", "N"),
+    ("regular", "N"),
+    ("remincross", "G"),
+    ("repulsiveforce", "G"),
+    ("resolution", "G"),
+    ("root", "GN"),
+    ("rotate", "G"),
+    ("rotation", "G"),
+    ("samehead", "E"),
+    ("sametail", "E"),
+    ("samplepoints", "N"),
+    ("scale", "G"),
+    ("searchsize", "G"),
+    ("sep", "G"),
+    ("shape", "N"),
+    ("shapefile", "N"),
+    ("showboxes", "ENG"),
+    ("sides", "N"),
+    ("size", "G"),
+    ("skew", "N"),
+    ("smoothing", "G"),
+    ("sortv", "GCN"),
+    ("splines", "G"),
+    ("start", "G"),
+    ("style", "ENCG"),
+    ("stylesheet", "G"),
+    ("tail_lp", "E"),
+    ("tailclip", "E"),
+    ("tailhref", "E"),
+    ("taillabel", "E"),
+    ("tailport", "E"),
+    ("tailtarget", "E"),
+    ("tailtooltip", "E"),
+    ("tailURL", "E"),
+    ("target", "ENGC"),
+    ("TBbalance", "G"),
+    ("tooltip", "NEC"),
+    ("truecolor", "G"),
+    ("URL", "ENGC"),
+    ("vertices", "N"),
+    ("viewport", "G"),
+    ("voro_margin", "G"),
+    ("weight", "E"),
+    ("width", "N"),
+    ("xdotversion", "G"),
+    ("xlabel", "EN"),
+    ("xlp", "NE"),
+    ("z", "N"),
+];
+
+/// Subgraphs accept cluster attributes too, since `cluster=true` makes any subgraph a cluster.
+fn is_known(name: &str, element: &Element) -> bool {
+    let usage = match element {
+        Element::Graph => "G",
+        Element::Subgraph(_) => "SC",
+        Element::Node(_) => "N",
+        Element::Edge { .. } => "E",
+    };
+    ATTRIBUTES
+        .iter()
+        .any(|(attr, used_by)| *attr == name && used_by.chars().any(|c| usage.contains(c)))
+}
+
+impl Graph {
+    /// Dangling edges, duplicate nodes and unknown attributes, in graph order: attributes,
+    /// nodes, edges, then each subgraph.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        check_attrs(&mut diagnostics, &self.attrs, Element::Graph);
+        self.check_body(
+            &mut diagnostics,
+            Element::Graph,
+            &self.nodes,
+            &self.edges,
+            &self.subgraphs,
+        );
+        diagnostics
+    }
+
+    fn check_body(
+        &self,
+        diagnostics: &mut Vec<Diagnostic>,
+        scope: Element,
+        nodes: &[Node],
+        edges: &[Edge],
+        subgraphs: &[Subgraph],
+    ) {
+        let mut seen = HashSet::new();
+        let mut reported = HashSet::new();
+        for node in nodes {
+            if !seen.insert(&node.id) && reported.insert(&node.id) {
+                diagnostics.push(Diagnostic::DuplicateNode {
+                    scope: scope.clone(),
+                    id: node.id.clone(),
+                });
+            }
+            check_attrs(diagnostics, &node.attrs, Element::Node(node.id.clone()));
+        }
+        for edge in edges {
+            let mut endpoints = vec![edge.from()];
+            if edge.to() != edge.from() {
+                endpoints.push(edge.to());
+            }
+            for missing in endpoints.into_iter().filter(|id| self.node(id).is_none()) {
+                diagnostics.push(Diagnostic::DanglingEdge {
+                    from: edge.from().to_string(),
+                    to: edge.to().to_string(),
+                    missing: missing.to_string(),
+                });
+            }
+            let element = Element::Edge {
+                from: edge.from().to_string(),
+                to: edge.to().to_string(),
+            };
+            check_attrs(diagnostics, edge.attrs(), element);
+        }
+        for subgraph in subgraphs {
+            let element = Element::Subgraph(subgraph.id.clone());
+            check_attrs(diagnostics, &subgraph.attrs, element.clone());
+            self.check_body(
+                diagnostics,
+                element,
+                &subgraph.nodes,
+                &subgraph.edges,
+                &subgraph.subgraphs,
+            );
+        }
+    }
+}
+
+fn check_attrs(
+    diagnostics: &mut Vec<Diagnostic>,
+    attrs: &HashMap<String, String>,
+    element: Element,
+) {
+    let mut names: Vec<_> = attrs
+        .keys()
+        .filter(|name| !is_known(name, &element))
+        .collect();
+    names.sort();
+    for name in names {
+        diagnostics.push(Diagnostic::UnknownAttr {
+            element: element.clone(),
+            name: name.clone(),
+        });
+    }
+}
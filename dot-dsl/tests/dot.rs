@@ -0,0 +1,88 @@
+use dot_dsl::graph::graph_items::edge::Edge;
+use dot_dsl::graph::graph_items::node::Node;
+use dot_dsl::graph::{Graph, Kind};
+
+#[test]
+fn test_empty_graph_to_dot() {
+    assert_eq!(Graph::new().to_string(), "graph {\n}");
+    assert_eq!(
+        Graph::new().with_kind(Kind::Digraph).to_string(),
+        "digraph {\n}"
+    );
+}
+
+#[test]
+fn test_graph_to_dot() {
+    let graph = Graph::new()
+        .with_attrs(&[("rankdir", "LR"), ("bgcolor", "white")])
+        .with_nodes(&[
+            Node::new("a").with_attrs(&[("shape", "box"), ("color", "green")]),
+            Node::new("b"),
+        ])
+        .with_edges(&[Edge::new("a", "b").with_attrs(&[("weight", "2")])]);
+
+    assert_eq!(
+        graph.to_string(),
+        "graph {\n    bgcolor=white;\n    rankdir=LR;\n    a [color=green, shape=box];\n    b;\n    a -- b [weight=2];\n}"
+    );
+}
+
+#[test]
+fn test_digraph_uses_arrow_edges() {
+    let graph = Graph::new()
+        .with_kind(Kind::Digraph)
+        .with_edges(&[Edge::new("a", "b")]);
+
+    assert_eq!(graph.to_string(), "digraph {\n    a -> b;\n}");
+}
+
+#[test]
+fn test_ids_are_quoted_when_needed() {
+    let graph = Graph::new().with_nodes(&[
+        Node::new("plain_id2"),
+        Node::new("-1.5"),
+        Node::new(".5"),
+        Node::new("two words"),
+        Node::new("2nd"),
+        Node::new("node"),
+        Node::new("Graph"),
+        Node::new(""),
+    ]);
+
+    assert_eq!(
+        graph.to_string(),
+        "graph {\n    plain_id2;\n    -1.5;\n    .5;\n    \"two words\";\n    \"2nd\";\n    \"node\";\n    \"Graph\";\n    \"\";\n}"
+    );
+}
+
+#[test]
+fn test_backslash_escapes_are_left_for_graphviz() {
+    let graph = Graph::new().with_nodes(&[Node::new("a").with_attrs(&[("label", "x\\ny \\\\ z")])]);
+
+    assert_eq!(
+        graph.to_string(),
+        "graph {\n    a [label=\"x\\ny \\\\ z\"];\n}"
+    );
+}
+
+#[test]
+fn test_quotes_are_escaped() {
+    let graph = Graph::new().with_nodes(&[
+        Node::new("a").with_attrs(&[("label", "say \"hi\"")]),
+        Node::new("b").with_attrs(&[("label", "ends in \\")]),
+    ]);
+
+    assert_eq!(
+        graph.to_string(),
+        "graph {\n    a [label=\"say \\\"hi\\\"\"];\n    b [label=\"ends in \\\\\"];\n}"
+    );
+}
+
+#[test]
+fn test_edge_accessors() {
+    let edge = Edge::new("a", "b").with_attrs(&[("color", "red")]);
+
+    assert_eq!(edge.from(), "a");
+    assert_eq!(edge.to(), "b");
+    assert_eq!(edge.attrs().get("color").map(String::as_str), Some("red"));
+}
@@ -0,0 +1,80 @@
+use dot_dsl::graph::graph_items::edge::Edge;
+use dot_dsl::graph::{Graph, Kind};
+
+#[test]
+fn test_graphs_are_undirected_and_not_strict_by_default() {
+    let graph = Graph::new();
+
+    assert_eq!(graph.kind, Kind::Graph);
+    assert!(!graph.strict);
+}
+
+#[test]
+fn test_kind_keyword_and_edge_op() {
+    assert_eq!(Kind::Graph.keyword(), "graph");
+    assert_eq!(Kind::Graph.edge_op(), "--");
+    assert_eq!(Kind::Digraph.keyword(), "digraph");
+    assert_eq!(Kind::Digraph.edge_op(), "->");
+}
+
+#[test]
+fn test_undirected_edges_join_either_way_round() {
+    let graph = Graph::new().with_edges(&[Edge::new("a", "b")]);
+
+    assert_eq!(graph.edge("b", "a"), Some(Edge::new("a", "b")));
+    assert_eq!(graph.with_kind(Kind::Digraph).edge("b", "a"), None);
+}
+
+#[test]
+fn test_duplicate_edges_are_kept_unless_strict() {
+    let edges = [
+        Edge::new("a", "b").with_attrs(&[("color", "red")]),
+        Edge::new("b", "a").with_attrs(&[("style", "dashed")]),
+    ];
+
+    assert_eq!(Graph::new().with_edges(&edges).edges.len(), 2);
+    assert_eq!(
+        Graph::new().with_strict(true).with_edges(&edges).edges,
+        vec![Edge::new("a", "b").with_attrs(&[("color", "red"), ("style", "dashed")])]
+    );
+    assert_eq!(
+        Graph::new()
+            .with_kind(Kind::Digraph)
+            .with_strict(true)
+            .with_edges(&edges)
+            .edges
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_making_a_graph_strict_merges_existing_edges() {
+    let graph = Graph::new()
+        .with_edges(&[Edge::new("a", "b"), Edge::new("a", "b")])
+        .with_strict(true);
+
+    assert_eq!(graph.edges, vec![Edge::new("a", "b")]);
+}
+
+#[test]
+fn test_strict_graphs_serialize_with_keyword() {
+    let graph = Graph::new()
+        .with_kind(Kind::Digraph)
+        .with_strict(true)
+        .with_edges(&[Edge::new("a", "b")]);
+
+    assert_eq!(graph.to_string(), "strict digraph {\n    a -> b;\n}");
+}
+
+#[test]
+fn test_parsing_strict_graph_merges_duplicate_edges() {
+    let graph: Graph = "strict graph { a -- b [color=red]; b -- a [weight=2] }"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        graph.edges,
+        vec![Edge::new("a", "b").with_attrs(&[("color", "red"), ("weight", "2")])]
+    );
+}
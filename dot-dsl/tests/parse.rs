@@ -0,0 +1,228 @@
+use dot_dsl::graph::graph_items::edge::Edge;
+use dot_dsl::graph::graph_items::node::Node;
+use dot_dsl::graph::{Graph, Kind};
+use dot_dsl::{ParseError, ParseErrorKind};
+
+#[test]
+fn test_parse_empty_graph() {
+    assert_eq!("graph {}".parse::<Graph>(), Ok(Graph::new()));
+    assert_eq!(
+        "strict digraph G { }".parse::<Graph>(),
+        Ok(Graph::new().with_kind(Kind::Digraph).with_strict(true))
+    );
+}
+
+#[test]
+fn test_parse_nodes_and_edges() {
+    let graph: Graph = "digraph {
+        a [color=green];
+        b
+        a -> b -> c [weight=2]
+    }"
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        graph.nodes,
+        vec![
+            Node::new("a").with_attrs(&[("color", "green")]),
+            Node::new("b"),
+            Node::new("c"),
+        ]
+    );
+    assert_eq!(
+        graph.edges,
+        vec![
+            Edge::new("a", "b").with_attrs(&[("weight", "2")]),
+            Edge::new("b", "c").with_attrs(&[("weight", "2")]),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_graph_attributes() {
+    let graph: Graph = "graph { rankdir=LR; graph [bgcolor=white, label=\"hi\"] }"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        graph,
+        Graph::new().with_attrs(&[("rankdir", "LR"), ("bgcolor", "white"), ("label", "hi")])
+    );
+}
+
+#[test]
+fn test_default_attributes_apply_to_later_statements() {
+    let graph: Graph = "graph {
+        a
+        node [shape=box]
+        edge [color=red]
+        b [shape=circle]
+        a -- c [style=dashed]
+    }"
+    .parse()
+    .unwrap();
+
+    assert_eq!(graph.node("a"), Some(Node::new("a")));
+    assert_eq!(
+        graph.node("b"),
+        Some(Node::new("b").with_attrs(&[("shape", "circle")]))
+    );
+    assert_eq!(
+        graph.node("c"),
+        Some(Node::new("c").with_attrs(&[("shape", "box")]))
+    );
+    assert_eq!(
+        graph.edges,
+        vec![Edge::new("a", "c").with_attrs(&[("color", "red"), ("style", "dashed")])]
+    );
+}
+
+#[test]
+fn test_comments_and_quoted_ids() {
+    let graph: Graph = "# preprocessor line
+        graph {
+            // line comment
+            \"two words\" [label=\"say \\\"hi\\\"\\n\" + \"bye\"] /* block
+            comment */
+            \"node\" -- -1.5
+        }"
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        graph.nodes,
+        vec![
+            Node::new("two words").with_attrs(&[("label", "say \"hi\"\\nbye")]),
+            Node::new("node"),
+            Node::new("-1.5"),
+        ]
+    );
+    assert_eq!(graph.edges, vec![Edge::new("node", "-1.5")]);
+}
+
+#[test]
+fn test_written_graphs_parse_back() {
+    let graph = Graph::new()
+        .with_attrs(&[
+            ("label", "a\\nb \\\\ \"c\" \\l"),
+            ("comment", "ends in \\\\"),
+            ("tooltip", "a\\\\\"b"),
+        ])
+        .with_nodes(&[
+            Node::new("graph").with_attrs(&[("color", "green")]),
+            Node::new("b c"),
+        ])
+        .with_edges(&[Edge::new("graph", "b c").with_attrs(&[("weight", "2")])]);
+
+    assert!(graph.to_string().contains(r#"label="a\nb \\ \"c\" \l";"#));
+    assert_eq!(graph.to_string().parse::<Graph>(), Ok(graph.clone()));
+    let graph = graph.with_kind(Kind::Digraph).with_strict(true);
+    assert_eq!(graph.to_string().parse::<Graph>(), Ok(graph));
+}
+
+#[test]
+fn test_backslashes_dot_cannot_express_are_normalised() {
+    for (value, normalised) in [
+        ("ends in \\", "ends in \\\\"),
+        ("a\\\"b", "a\\\\\"b"),
+        ("three \\\\\\", "three \\\\\\\\"),
+    ] {
+        let graph = Graph::new().with_attrs(&[("label", value)]);
+        let parsed: Graph = graph.to_string().parse().unwrap();
+
+        assert_eq!(parsed.attr("label"), Some(normalised));
+        assert_eq!(parsed.to_string(), graph.to_string());
+        assert_eq!(parsed.to_string().parse::<Graph>(), Ok(parsed));
+    }
+}
+
+#[test]
+fn test_error_reports_line_and_column() {
+    assert_eq!(
+        "graph {\n  a -- ;\n}".parse::<Graph>(),
+        Err(ParseError {
+            line: 2,
+            column: 8,
+            kind: ParseErrorKind::UnexpectedToken {
+                expected: "an id",
+                found: ";".into()
+            }
+        })
+    );
+}
+
+#[test]
+fn test_edge_op_must_match_graph_kind() {
+    assert_eq!(
+        "graph { a -> b }".parse::<Graph>(),
+        Err(ParseError {
+            line: 1,
+            column: 11,
+            kind: ParseErrorKind::MismatchedEdgeOp("->")
+        })
+    );
+}
+
+#[test]
+fn test_lexical_errors() {
+    let kind = |source: &str| source.parse::<Graph>().unwrap_err().kind;
+
+    assert_eq!(
+        kind("graph { a [label=\"x] }"),
+        ParseErrorKind::UnterminatedString
+    );
+    assert_eq!(kind("graph { /* a }"), ParseErrorKind::UnterminatedComment);
+    assert_eq!(kind("graph { a @ b }"), ParseErrorKind::UnexpectedChar('@'));
+}
+
+#[test]
+fn test_unexpected_end() {
+    assert_eq!(
+        "digraph {\n  a".parse::<Graph>(),
+        Err(ParseError {
+            line: 2,
+            column: 4,
+            kind: ParseErrorKind::UnexpectedEnd {
+                expected: "a statement or }"
+            }
+        })
+    );
+    assert_eq!(
+        "graph {} extra".parse::<Graph>().unwrap_err().kind,
+        ParseErrorKind::UnexpectedToken {
+            expected: "end of input",
+            found: "extra".into()
+        }
+    );
+}
+
+#[test]
+fn test_ports_become_edge_attributes() {
+    let graph: Graph = "digraph { a:p -> b:q:ne -> c [color=red]; a:n [shape=box] }"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        graph.edges,
+        vec![
+            Edge::new("a", "b").with_attrs(&[
+                ("tailport", "p"),
+                ("headport", "q:ne"),
+                ("color", "red")
+            ]),
+            Edge::new("b", "c").with_attrs(&[("tailport", "q:ne"), ("color", "red")]),
+        ]
+    );
+    assert_eq!(
+        graph.node("a"),
+        Some(Node::new("a").with_attrs(&[("shape", "box")]))
+    );
+    assert_eq!(
+        "graph { a: -- b }".parse::<Graph>().unwrap_err().kind,
+        ParseErrorKind::UnexpectedToken {
+            expected: "an id",
+            found: "--".into()
+        }
+    );
+}
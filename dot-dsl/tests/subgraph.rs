@@ -0,0 +1,225 @@
+use dot_dsl::graph::graph_items::edge::Edge;
+use dot_dsl::graph::graph_items::node::Node;
+use dot_dsl::graph::graph_items::subgraph::Subgraph;
+use dot_dsl::graph::{Graph, Kind};
+
+fn clustered() -> Graph {
+    let inner =
+        Subgraph::new("inner").with_nodes(&[Node::new("c").with_attrs(&[("shape", "box")])]);
+    let cluster = Subgraph::new("cluster_x")
+        .with_attrs(&[("label", "X")])
+        .with_nodes(&[Node::new("b")])
+        .with_edges(&[Edge::new("b", "c")])
+        .with_subgraphs(&[inner]);
+    Graph::new()
+        .with_kind(Kind::Digraph)
+        .with_nodes(&[Node::new("a")])
+        .with_edges(&[Edge::new("a", "b")])
+        .with_subgraphs(&[cluster])
+}
+
+#[test]
+fn test_empty_subgraph() {
+    let subgraph = Subgraph::new("cluster_a");
+
+    assert!(subgraph.nodes.is_empty());
+    assert!(subgraph.edges.is_empty());
+    assert!(subgraph.attrs.is_empty());
+    assert!(subgraph.subgraphs.is_empty());
+    assert!(subgraph.is_cluster());
+    assert!(!Subgraph::new("a").is_cluster());
+}
+
+#[test]
+fn test_node_lookup_searches_subgraphs() {
+    let graph = clustered();
+
+    assert_eq!(graph.node("a"), Some(Node::new("a")));
+    assert_eq!(graph.node("b"), Some(Node::new("b")));
+    assert_eq!(
+        graph.node("c"),
+        Some(Node::new("c").with_attrs(&[("shape", "box")]))
+    );
+    assert_eq!(graph.node("d"), None);
+}
+
+#[test]
+fn test_edge_and_subgraph_lookup_search_subgraphs() {
+    let graph = clustered();
+
+    assert_eq!(graph.edge("b", "c"), Some(Edge::new("b", "c")));
+    assert_eq!(graph.edge("c", "b"), None);
+    assert_eq!(graph.subgraph("inner").map(|s| s.nodes.len()), Some(1));
+    assert_eq!(
+        graph
+            .subgraph("cluster_x")
+            .and_then(|s| s.node("c"))
+            .map(|n| n.id),
+        Some("c".to_string())
+    );
+    assert_eq!(graph.subgraph("missing"), None);
+}
+
+#[test]
+fn test_subgraphs_to_dot() {
+    assert_eq!(
+        clustered().to_string(),
+        "digraph {
+    a;
+    subgraph cluster_x {
+        label=X;
+        b;
+        subgraph inner {
+            c [shape=box];
+        }
+        b -> c;
+    }
+    a -> b;
+}"
+    );
+}
+
+#[test]
+fn test_parse_subgraphs() {
+    let graph: Graph = "digraph {
+        a
+        subgraph cluster_x {
+            label=X
+            b
+            subgraph inner { c [shape=box] }
+            b -> c
+        }
+        a -> b
+    }"
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        graph.subgraphs[0].subgraphs,
+        clustered().subgraphs[0].subgraphs
+    );
+    assert_eq!(graph.edge("a", "b"), Some(Edge::new("a", "b")));
+    assert_eq!(graph.subgraphs[0].edges, vec![Edge::new("b", "c")]);
+    assert_eq!(graph.to_string().parse::<Graph>(), Ok(graph));
+}
+
+#[test]
+fn test_edge_endpoints_become_members_of_the_subgraph() {
+    let graph: Graph = "digraph { a; subgraph cluster_x { b; b -> c } a -> b }"
+        .parse()
+        .unwrap();
+
+    assert_eq!(graph.nodes, vec![Node::new("a"), Node::new("b")]);
+    assert_eq!(
+        graph.subgraphs[0].nodes,
+        vec![Node::new("b"), Node::new("c")]
+    );
+}
+
+#[test]
+fn test_parse_anonymous_subgraphs() {
+    let graph: Graph = "graph { { rank=same; a b } subgraph { c } }"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        graph.subgraphs,
+        vec![
+            Subgraph::new("")
+                .with_attrs(&[("rank", "same")])
+                .with_nodes(&[Node::new("a"), Node::new("b")]),
+            Subgraph::new("").with_nodes(&[Node::new("c")]),
+        ]
+    );
+    assert_eq!(
+        graph.to_string(),
+        "graph {\n    subgraph {\n        rank=same;\n        a;\n        b;\n    }\n    subgraph {\n        c;\n    }\n}"
+    );
+}
+
+#[test]
+fn test_defaults_inside_subgraphs_stay_inside() {
+    let graph: Graph = "graph {
+        node [color=red]
+        a
+        subgraph s { node [shape=box]; a; b }
+        c
+    }"
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        graph.nodes,
+        vec![
+            Node::new("a").with_attrs(&[("color", "red")]),
+            Node::new("c").with_attrs(&[("color", "red")]),
+        ]
+    );
+    assert_eq!(
+        graph.subgraphs[0].nodes,
+        vec![
+            Node::new("a"),
+            Node::new("b").with_attrs(&[("color", "red"), ("shape", "box")]),
+        ]
+    );
+}
+
+#[test]
+fn test_strict_graphs_merge_duplicate_edges_across_subgraphs() {
+    let graph: Graph =
+        "strict graph { a -- b [color=red]; subgraph s { b -- a [weight=2]; a -- b; c -- d } }"
+            .parse()
+            .unwrap();
+
+    assert_eq!(
+        graph.edges,
+        vec![Edge::new("a", "b").with_attrs(&[("color", "red"), ("weight", "2")])]
+    );
+    assert_eq!(graph.subgraphs[0].edges, vec![Edge::new("c", "d")]);
+    assert_eq!(graph.to_string().matches("--").count(), 2);
+
+    let subgraph = || Subgraph::new("s").with_edges(&[Edge::new("b", "a"), Edge::new("a", "b")]);
+    let graph = Graph::new()
+        .with_edges(&[Edge::new("a", "b")])
+        .with_subgraphs(&[subgraph()]);
+    assert_eq!(graph.subgraphs[0].edges.len(), 2);
+    assert_eq!(graph.with_strict(true).subgraphs[0].edges, vec![]);
+    assert_eq!(
+        Graph::new()
+            .with_strict(true)
+            .with_subgraphs(&[subgraph()])
+            .subgraphs[0]
+            .edges,
+        vec![Edge::new("b", "a")]
+    );
+}
+
+#[test]
+fn test_subgraphs_as_edge_endpoints() {
+    let graph: Graph = "digraph {
+        a -> {b c} [color=red]
+        subgraph cluster_x { d; subgraph { e } } -> f
+    }"
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        graph.edges,
+        vec![
+            Edge::new("a", "b").with_attrs(&[("color", "red")]),
+            Edge::new("a", "c").with_attrs(&[("color", "red")]),
+            Edge::new("d", "f"),
+            Edge::new("e", "f"),
+        ]
+    );
+    assert_eq!(graph.nodes, vec![Node::new("a"), Node::new("f")]);
+    assert_eq!(
+        graph.subgraphs[0],
+        Subgraph::new("").with_nodes(&[Node::new("b"), Node::new("c")])
+    );
+    assert_eq!(
+        graph.subgraph("cluster_x").and_then(|s| s.node("e")),
+        Some(Node::new("e"))
+    );
+    assert_eq!(graph.validate(), vec![]);
+}
@@ -0,0 +1,138 @@
+use dot_dsl::graph::graph_items::edge::Edge;
+use dot_dsl::graph::graph_items::node::Node;
+use dot_dsl::graph::graph_items::subgraph::Subgraph;
+use dot_dsl::graph::Graph;
+use dot_dsl::{Diagnostic, Element};
+
+#[test]
+fn test_valid_graph_has_no_diagnostics() {
+    let graph = Graph::new()
+        .with_attrs(&[("rankdir", "LR")])
+        .with_nodes(&[
+            Node::new("a").with_attrs(&[("shape", "box")]),
+            Node::new("b"),
+        ])
+        .with_edges(&[Edge::new("a", "b").with_attrs(&[("weight", "2")])]);
+
+    assert_eq!(graph.validate(), vec![]);
+    assert_eq!(Graph::new().validate(), vec![]);
+}
+
+#[test]
+fn test_dangling_edge_endpoints() {
+    let graph = Graph::new()
+        .with_nodes(&[Node::new("a")])
+        .with_edges(&[Edge::new("a", "b"), Edge::new("c", "c")]);
+
+    assert_eq!(
+        graph.validate(),
+        vec![
+            Diagnostic::DanglingEdge {
+                from: "a".into(),
+                to: "b".into(),
+                missing: "b".into()
+            },
+            Diagnostic::DanglingEdge {
+                from: "c".into(),
+                to: "c".into(),
+                missing: "c".into()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_duplicate_nodes_are_reported_once() {
+    let graph = Graph::new().with_nodes(&[
+        Node::new("a"),
+        Node::new("b"),
+        Node::new("a"),
+        Node::new("a"),
+    ]);
+
+    assert_eq!(
+        graph.validate(),
+        vec![Diagnostic::DuplicateNode {
+            scope: Element::Graph,
+            id: "a".into()
+        }]
+    );
+}
+
+#[test]
+fn test_unknown_attributes_depend_on_the_element() {
+    let graph = Graph::new()
+        .with_attrs(&[("shape", "box"), ("colour", "red")])
+        .with_nodes(&[Node::new("a").with_attrs(&[("weight", "2"), ("shape", "box")])])
+        .with_edges(&[Edge::new("a", "a").with_attrs(&[("shape", "box")])]);
+
+    assert_eq!(
+        graph.validate(),
+        vec![
+            Diagnostic::UnknownAttr {
+                element: Element::Graph,
+                name: "colour".into()
+            },
+            Diagnostic::UnknownAttr {
+                element: Element::Graph,
+                name: "shape".into()
+            },
+            Diagnostic::UnknownAttr {
+                element: Element::Node("a".into()),
+                name: "weight".into()
+            },
+            Diagnostic::UnknownAttr {
+                element: Element::Edge {
+                    from: "a".into(),
+                    to: "a".into()
+                },
+                name: "shape".into()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_subgraphs_are_validated() {
+    let cluster = Subgraph::new("cluster_x")
+        .with_attrs(&[("label", "X"), ("rank", "same"), ("rankdir", "LR")])
+        .with_nodes(&[Node::new("b"), Node::new("b")])
+        .with_edges(&[Edge::new("a", "b"), Edge::new("b", "c")]);
+    let other = Subgraph::new("other").with_nodes(&[Node::new("b"), Node::new("b")]);
+    let graph = Graph::new()
+        .with_nodes(&[Node::new("a")])
+        .with_edges(&[Edge::new("a", "b")])
+        .with_subgraphs(&[cluster, other]);
+
+    assert_eq!(
+        graph.validate(),
+        vec![
+            Diagnostic::UnknownAttr {
+                element: Element::Subgraph("cluster_x".into()),
+                name: "rankdir".into()
+            },
+            Diagnostic::DuplicateNode {
+                scope: Element::Subgraph("cluster_x".into()),
+                id: "b".into()
+            },
+            Diagnostic::DanglingEdge {
+                from: "b".into(),
+                to: "c".into(),
+                missing: "c".into()
+            },
+            Diagnostic::DuplicateNode {
+                scope: Element::Subgraph("other".into()),
+                id: "b".into()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parsed_graphs_have_no_dangling_edges() {
+    let graph: Graph = "digraph { a -> b; subgraph cluster_x { c -> a } }"
+        .parse()
+        .unwrap();
+
+    assert_eq!(graph.validate(), vec![]);
+}